@@ -1,8 +1,35 @@
+use num_complex::Complex;
+
 /// Pair(re, im) for a single complex.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Pair<T>(pub T, pub T);
 /// VecShape(elements in row major order, shape of array) for any type T.
+#[derive(Debug, Clone, PartialEq)]
 pub struct VecShape<T>(pub Vec<T>, pub Vec<u64>);
 /// VecVec(re vector, im vector) for complex vector.
+#[derive(Debug, Clone, PartialEq)]
 pub struct VecVec<T>(pub Vec<T>, pub Vec<T>);
 /// VecVecShape(real elements in row major order, imaginary elements in row major order, shape of array) for complex array.
-pub struct VecVecShape<T>(pub Vec<T>, pub Vec<T>, pub Vec<u64>);
\ No newline at end of file
+#[derive(Debug, Clone, PartialEq)]
+pub struct VecVecShape<T>(pub Vec<T>, pub Vec<T>, pub Vec<u64>);
+
+/// AnyArray holds the data in the narrowest native type, which is chosen at runtime. See `ArrayObject::into_any()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyArray {
+    U8(VecShape<u8>),
+    U16(VecShape<u16>),
+    U32(VecShape<u32>),
+    U64(VecShape<u64>),
+    U128(VecShape<u128>),
+    I8(VecShape<i8>),
+    I16(VecShape<i16>),
+    I32(VecShape<i32>),
+    I64(VecShape<i64>),
+    I128(VecShape<i128>),
+    F32(VecShape<f32>),
+    F64(VecShape<f64>),
+    C32(VecShape<Complex<f32>>),
+    C64(VecShape<Complex<f64>>),
+    Str(VecShape<String>),
+    Bytes(VecShape<Vec<u8>>),
+}
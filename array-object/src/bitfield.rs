@@ -1,3 +1,5 @@
+#![allow(clippy::unusual_byte_groupings)]
+
 use crate::DataType;
 
 pub const TYPE_MASK: u8 = 0b_111_0_0000;
@@ -9,6 +11,7 @@ pub const SIGNED_INTEGER: u8 = 0b_011_0_0000;
 pub const REAL: u8 = 0b_100_0_0000;
 pub const COMPLEX: u8 = 0b_101_0_0000;
 pub const STRING: u8 = 0b_110_0_0000;
+pub const EXTENSION: u8 = 0b_111_0_0000;
 
 pub const SHORTDATA_MASK: u8 = 0b_000_1_1111;
 
@@ -22,6 +25,17 @@ pub const VARIABLE_LENGTH: u8 = 0b_000_1_0000;
 pub const JOINED: u8 = 0b_000_0_0000;
 pub const DICTIONARY: u8 = 0b_000_1_0000;
 
+pub const VERSION_MASK: u8 = 0b_000_1_1111;
+
+pub const EXTENSION_VERSION: u8 = 0;
+
+pub const SPARSE: u8 = 0;
+pub const NULLABLE: u8 = 1;
+pub const BYTES: u8 = 2;
+pub const UNIT: u8 = 3;
+pub const CHECKSUM: u8 = 4;
+pub const DIMENSION: u8 = 5;
+
 impl DataType {
     /// Reads the last byte of binary data and describes the data type.
     pub fn describe_footer(binary: &Vec<u8>) -> String {
@@ -91,9 +105,20 @@ impl DataType {
                     format!("{}-dimensional joined string", footer & DIMENSION_MASK)
                 }
             }
-            _ => {
-                panic!();
+            EXTENSION if footer & VERSION_MASK > EXTENSION_VERSION => {
+                format!("Extension of version {}", footer & VERSION_MASK)
             }
+            EXTENSION => match binary.len().checked_sub(2).map(|i| binary[i]) {
+                Some(SPARSE) => "Sparse array".to_string(),
+                Some(NULLABLE) => "Nullable array".to_string(),
+                Some(BYTES) => "Bytes array".to_string(),
+                Some(UNIT) => "Array with unit".to_string(),
+                Some(CHECKSUM) => "Array with checksum".to_string(),
+                Some(DIMENSION) => "Array of more than 15 dimensions".to_string(),
+                Some(code) => format!("Extension {code}"),
+                None => "Broken extension".to_string(),
+            },
+            _ => unreachable!(),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,322 @@
+use crate::convert::cast::*;
+use crate::storage::*;
+use crate::unit::Unit;
+use num_complex::Complex;
+use std::fmt::Display;
+
+/// The result of `ArrayObject::diff()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diff {
+    /// The shapes (self, other) if they differ. The elements are not compared in this case.
+    pub shape: Option<(Vec<usize>, Vec<usize>)>,
+    /// The data types (self, other) if they differ. Numbers are still compared along the hierarchy of integer ⊂ real ⊂ complex.
+    pub datatype: Option<(DataType, DataType)>,
+    /// The units (self, other) if they differ. The raw values are still compared.
+    pub unit: Option<(Option<Unit>, Option<Unit>)>,
+    /// The number of elements out of the tolerance.
+    pub mismatches: usize,
+    /// The maximum absolute error.
+    pub max_abs_error: f64,
+    /// The maximum relative error with respect to the other.
+    pub max_rel_error: f64,
+    /// The indices of the first mismatched elements.
+    pub indices: Vec<Vec<usize>>,
+}
+
+impl Diff {
+    /// Returns true if the shapes, types and units agree and all the elements are within the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.shape.is_none()
+            && self.datatype.is_none()
+            && self.unit.is_none()
+            && self.mismatches == 0
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((lhs, rhs)) = &self.shape {
+            return write!(f, "Shape mismatch: {lhs:?} != {rhs:?}");
+        }
+        if let Some((lhs, rhs)) = &self.datatype {
+            writeln!(f, "Data type mismatch: {lhs:?} != {rhs:?}")?;
+        }
+        if let Some((lhs, rhs)) = &self.unit {
+            writeln!(f, "Unit mismatch: {lhs:?} != {rhs:?}")?;
+        }
+        write!(
+            f,
+            "{} mismatched elements, max absolute error {:e}, max relative error {:e}, first indices {:?}",
+            self.mismatches, self.max_abs_error, self.max_rel_error, self.indices
+        )
+    }
+}
+
+/// Returns the bytes of each integer without the trailing zeros, which compare equal if the values are equal.
+fn trimmed_integers(obj: &ArrayObject) -> Vec<Vec<u8>> {
+    let len = obj.len();
+    if len == 0 {
+        return vec![];
+    }
+    obj.data
+        .chunks(obj.data.len() / len)
+        .map(|x| {
+            let n = x.len() - x.iter().rev().take_while(|&&b| b == 0).count();
+            x[..n].to_vec()
+        })
+        .collect()
+}
+
+/// An integer as the sign and the magnitude, covering both i128 and u128.
+#[derive(Clone, Copy, PartialEq)]
+struct Integer {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl Integer {
+    fn abs_diff(self, other: Integer) -> f64 {
+        if self.negative == other.negative {
+            self.magnitude.abs_diff(other.magnitude) as f64
+        } else {
+            self.magnitude as f64 + other.magnitude as f64
+        }
+    }
+    fn to_f64(self) -> f64 {
+        match self.negative {
+            true => -(self.magnitude as f64),
+            false => self.magnitude as f64,
+        }
+    }
+}
+
+enum Elements {
+    Integer(Vec<Integer>),
+    Float(Vec<Complex<f64>>),
+    String(Vec<Vec<u8>>),
+}
+
+impl Elements {
+    fn read(obj: &ArrayObject, wide: bool) -> Self {
+        if wide
+            && matches!(
+                obj.datatype,
+                DataType::UnsignedInteger | DataType::SignedInteger
+            )
+        {
+            return Elements::String(trimmed_integers(obj));
+        }
+        match obj.datatype {
+            DataType::UnsignedInteger => Elements::Integer(
+                read_unsigned(obj)
+                    .into_iter()
+                    .map(|x| Integer {
+                        negative: false,
+                        magnitude: x,
+                    })
+                    .collect(),
+            ),
+            DataType::SignedInteger => Elements::Integer(
+                read_signed(obj)
+                    .into_iter()
+                    .map(|x| Integer {
+                        negative: x < 0,
+                        magnitude: x.unsigned_abs(),
+                    })
+                    .collect(),
+            ),
+            DataType::Real => Elements::Float(
+                read_float(obj)
+                    .into_iter()
+                    .map(|x| Complex::new(x, 0.0))
+                    .collect(),
+            ),
+            DataType::Complex => Elements::Float(
+                read_float(obj)
+                    .chunks(2)
+                    .map(|x| Complex::new(x[0], x[1]))
+                    .collect(),
+            ),
+            DataType::String | DataType::Bytes => Elements::String(
+                obj.elements()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|x| x.to_vec())
+                    .collect(),
+            ),
+        }
+    }
+    fn into_float(self) -> Self {
+        match self {
+            Elements::Integer(v) => Elements::Float(
+                v.into_iter()
+                    .map(|x| Complex::new(x.to_f64(), 0.0))
+                    .collect(),
+            ),
+            _ => self,
+        }
+    }
+}
+
+impl ArrayObject {
+    /// Compares the values regardless of the size of the type in which they are stored. NaN is equal to NaN.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let a: ArrayObject = vec![1u8, 2, 3].into();
+    /// let b: ArrayObject = vec![1u64, 2, 3].into();
+    /// assert_ne!(a, b);
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &ArrayObject) -> bool {
+        self.compare(other, 0.0, 0.0, true, 0).is_match()
+    }
+    /// Compares the numbers with the tolerance, |self - other| <= atol + rtol * |other|. NaN is not equal to anything.
+    pub fn approx_eq(&self, other: &ArrayObject, rtol: f64, atol: f64) -> bool {
+        self.compare(other, rtol, atol, false, 0).is_match()
+    }
+    /// Reports the differences of the shape, the type and the elements. At most `max_indices` indices of the mismatched elements are recorded.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let a: ArrayObject = vec![1.0f64, 2.0, 3.0].into();
+    /// let b: ArrayObject = vec![1.0f64, 2.5, 3.0].into();
+    /// let diff = a.diff(&b, 1e-6, 0.0, 10);
+    /// assert_eq!(diff.mismatches, 1);
+    /// assert_eq!(diff.indices, vec![vec![1]]);
+    /// ```
+    pub fn diff(&self, other: &ArrayObject, rtol: f64, atol: f64, max_indices: usize) -> Diff {
+        self.compare(other, rtol, atol, false, max_indices)
+    }
+    fn compare(
+        &self,
+        other: &ArrayObject,
+        rtol: f64,
+        atol: f64,
+        equal_nan: bool,
+        max_indices: usize,
+    ) -> Diff {
+        let mut diff = Diff {
+            shape: None,
+            datatype: None,
+            unit: None,
+            mismatches: 0,
+            max_abs_error: 0.0,
+            max_rel_error: 0.0,
+            indices: vec![],
+        };
+        if self.datatype != other.datatype {
+            diff.datatype = Some((self.datatype.clone(), other.datatype.clone()));
+        }
+        if self.unit != other.unit {
+            diff.unit = Some((self.unit, other.unit));
+        }
+        if self.shape != other.shape {
+            diff.shape = Some((self.shape(), other.shape()));
+            return diff;
+        }
+        let (lhs_validity, rhs_validity) = (self.validity(), other.validity());
+        let null = |i: usize| {
+            (
+                lhs_validity
+                    .as_ref()
+                    .is_some_and(|v| v.get(i) == Some(&false)),
+                rhs_validity
+                    .as_ref()
+                    .is_some_and(|v| v.get(i) == Some(&false)),
+            )
+        };
+        let wide = is_wide(self) || is_wide(other);
+        let (lhs, rhs) = match (Elements::read(self, wide), Elements::read(other, wide)) {
+            (Elements::Integer(a), Elements::Integer(b)) => {
+                (Elements::Integer(a), Elements::Integer(b))
+            }
+            (Elements::String(a), Elements::String(b)) => {
+                diff.mismatches =
+                    mismatched_strings(&a, &b, null, max_indices, &mut diff.indices, &self.shape);
+                return diff;
+            }
+            (Elements::String(_), _) | (_, Elements::String(_)) => return diff,
+            (a, b) => (a.into_float(), b.into_float()),
+        };
+        let mut record = |i: usize, abs: f64, rel: f64, matched: bool| {
+            let (abs, rel, matched) = match null(i) {
+                (false, false) => (abs, rel, matched),
+                (a, b) => (0.0, 0.0, a == b),
+            };
+            if !matched {
+                diff.mismatches += 1;
+                if diff.indices.len() < max_indices {
+                    diff.indices.push(unravel(i, &self.shape));
+                }
+            }
+            if abs > diff.max_abs_error {
+                diff.max_abs_error = abs;
+            }
+            if rel > diff.max_rel_error {
+                diff.max_rel_error = rel;
+            }
+        };
+        match (lhs, rhs) {
+            (Elements::Integer(a), Elements::Integer(b)) => {
+                for (i, (x, y)) in a.into_iter().zip(b).enumerate() {
+                    let abs = x.abs_diff(y);
+                    let rel = abs / y.magnitude as f64;
+                    record(
+                        i,
+                        abs,
+                        rel,
+                        x == y || abs <= atol + rtol * y.magnitude as f64,
+                    );
+                }
+            }
+            (Elements::Float(a), Elements::Float(b)) => {
+                for (i, (x, y)) in a.into_iter().zip(b).enumerate() {
+                    if x.is_nan() || y.is_nan() {
+                        record(i, 0.0, 0.0, equal_nan && x.is_nan() && y.is_nan());
+                        continue;
+                    }
+                    let abs = (x - y).norm();
+                    let rel = abs / y.norm();
+                    record(i, abs, rel, x == y || abs <= atol + rtol * y.norm());
+                }
+            }
+            _ => unreachable!(),
+        }
+        diff
+    }
+}
+
+fn mismatched_strings(
+    a: &[Vec<u8>],
+    b: &[Vec<u8>],
+    null: impl Fn(usize) -> (bool, bool),
+    max_indices: usize,
+    indices: &mut Vec<Vec<usize>>,
+    shape: &[u64],
+) -> usize {
+    let mut mismatches = 0;
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        let matched = match null(i) {
+            (false, false) => x == y,
+            (a, b) => a == b,
+        };
+        if !matched {
+            mismatches += 1;
+            if indices.len() < max_indices {
+                indices.push(unravel(i, shape));
+            }
+        }
+    }
+    mismatches
+}
+
+/// Converts the index of the flattened array into the multi-dimensional index.
+fn unravel(mut i: usize, shape: &[u64]) -> Vec<usize> {
+    let mut index = vec![0; shape.len()];
+    for (k, n) in shape.iter().enumerate().rev() {
+        index[k] = i % *n as usize;
+        i /= *n as usize;
+    }
+    index
+}
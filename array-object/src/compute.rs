@@ -0,0 +1,499 @@
+use crate::CastOption;
+use crate::convert::cast::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::Unit;
+use num_complex::Complex;
+
+/// Decoded elements used for the computation.
+enum Values {
+    Unsigned(Vec<u128>),
+    Signed(Vec<i128>),
+    Real(Vec<f64>),
+    Complex(Vec<Complex<f64>>),
+}
+
+impl Values {
+    fn read(obj: &ArrayObject, op: &'static str) -> Result<Self, ArrayObjectError> {
+        if obj.null_count() > 0 {
+            return Err(ArrayObjectError::NullElement);
+        }
+        if is_wide(obj) {
+            return Err(ArrayObjectError::Overflow);
+        }
+        match obj.datatype {
+            DataType::UnsignedInteger => Ok(Values::Unsigned(read_unsigned(obj))),
+            DataType::SignedInteger => Ok(Values::Signed(read_signed(obj))),
+            DataType::Real => Ok(Values::Real(read_float(obj))),
+            DataType::Complex => Ok(Values::Complex(
+                read_float(obj)
+                    .chunks(2)
+                    .map(|x| Complex::new(x[0], x[1]))
+                    .collect(),
+            )),
+            DataType::String | DataType::Bytes => Err(ArrayObjectError::UndefinedOperation(
+                op,
+                obj.datatype.clone(),
+            )),
+        }
+    }
+    fn write(self, shape: Vec<u64>) -> ArrayObject {
+        let (data, datatype) = match self {
+            Values::Unsigned(v) => (write_unsigned(v), DataType::UnsignedInteger),
+            Values::Signed(v) => (write_signed(v), DataType::SignedInteger),
+            Values::Real(v) => (write_float(v), DataType::Real),
+            Values::Complex(v) => (
+                write_float(v.into_iter().flat_map(|x| [x.re, x.im]).collect()),
+                DataType::Complex,
+            ),
+        };
+        ArrayObject {
+            data,
+            shape,
+            datatype,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+/// Applies the function to each lane along the axis. If the axis is None, the whole array is treated as a single lane.
+fn lanes<T: Copy, R>(
+    val: &[T],
+    shape: &[u64],
+    axis: Option<usize>,
+    f: impl Fn(&[T]) -> Result<R, ArrayObjectError>,
+) -> Result<(Vec<R>, Vec<u64>), ArrayObjectError> {
+    let Some(axis) = axis else {
+        return Ok((vec![f(val)?], vec![]));
+    };
+    if axis >= shape.len() {
+        return Err(ArrayObjectError::AxisOutOfRange(axis, shape.len()));
+    }
+    let outer = shape[..axis].iter().product::<u64>() as usize;
+    let n = shape[axis] as usize;
+    let inner = shape[axis + 1..].iter().product::<u64>() as usize;
+    let mut res = Vec::with_capacity(outer * inner);
+    let mut lane = Vec::with_capacity(n);
+    for i in 0..outer {
+        for j in 0..inner {
+            lane.clear();
+            lane.extend((0..n).map(|k| val[(i * n + k) * inner + j]));
+            res.push(f(&lane)?);
+        }
+    }
+    let mut shape = shape.to_vec();
+    shape.remove(axis);
+    Ok((res, shape))
+}
+
+fn position<T: Copy>(x: &[T], better: impl Fn(T, T) -> bool) -> Result<usize, ArrayObjectError> {
+    if x.is_empty() {
+        return Err(ArrayObjectError::EmptyArray);
+    }
+    let mut pos = 0;
+    for (i, v) in x.iter().enumerate().skip(1) {
+        if better(*v, x[pos]) {
+            pos = i;
+        }
+    }
+    Ok(pos)
+}
+
+fn greater_real(a: f64, b: f64) -> bool {
+    a > b || (b.is_nan() && !a.is_nan())
+}
+
+fn less_real(a: f64, b: f64) -> bool {
+    a < b || (b.is_nan() && !a.is_nan())
+}
+
+/// Returns the common data type along the hierarchy of integer ⊂ real ⊂ complex.
+fn common_datatype(a: &DataType, b: &DataType) -> DataType {
+    let rank = |x: &DataType| match x {
+        DataType::UnsignedInteger => 0,
+        DataType::SignedInteger => 1,
+        DataType::Real => 2,
+        DataType::Complex => 3,
+        DataType::String => 4,
+        DataType::Bytes => 5,
+    };
+    if rank(a) >= rank(b) {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+impl ArrayObject {
+    /// Returns the sum of the elements, or the sums along the axis. The units of decimal numbers and durations are kept.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// use array_object::adaptor::VecShape;
+    /// let obj: ArrayObject = VecShape(vec![1u8, 2, 3, 4], vec![2, 2]).try_into().unwrap();
+    /// let sum: u32 = obj.sum(None).unwrap().try_into().unwrap();
+    /// assert_eq!(sum, 10);
+    /// let sum: Vec<u32> = obj.sum(Some(0)).unwrap().try_into().unwrap();
+    /// assert_eq!(sum, vec![4, 6]);
+    /// ```
+    pub fn sum(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        let res = match Values::read(self, "sum")? {
+            Values::Unsigned(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, |x| {
+                    x.iter()
+                        .try_fold(0u128, |a, b| a.checked_add(*b))
+                        .ok_or(ArrayObjectError::Overflow)
+                })?;
+                Values::Unsigned(r).write(shape)
+            }
+            Values::Signed(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, |x| {
+                    x.iter()
+                        .try_fold(0i128, |a, b| a.checked_add(*b))
+                        .ok_or(ArrayObjectError::Overflow)
+                })?;
+                Values::Signed(r).write(shape)
+            }
+            Values::Real(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, |x| Ok(x.iter().sum()))?;
+                Values::Real(r).write(shape)
+            }
+            Values::Complex(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, |x| Ok(x.iter().sum()))?;
+                Values::Complex(r).write(shape)
+            }
+        };
+        Ok(ArrayObject {
+            unit: self
+                .unit
+                .filter(|x| matches!(x, Unit::Decimal(_) | Unit::Duration(_))),
+            ..res
+        })
+    }
+    /// Returns the arithmetic mean of the elements as real or complex numbers. Decimal numbers are scaled by the exponent.
+    pub fn mean(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        fn mean<T: Copy + std::iter::Sum<T> + std::ops::Div<f64, Output = T>>(
+            x: &[T],
+        ) -> Result<T, ArrayObjectError> {
+            if x.is_empty() {
+                return Err(ArrayObjectError::EmptyArray);
+            }
+            Ok(x.iter().copied().sum::<T>() / x.len() as f64)
+        }
+        let scaled;
+        let obj = match self.unit {
+            Some(Unit::Decimal(_)) => {
+                scaled = self.clone().descale(true, false)?;
+                &scaled
+            }
+            _ => self,
+        };
+        let res = match Values::read(obj, "mean")? {
+            Values::Unsigned(v) => {
+                let v: Vec<_> = v.into_iter().map(|x| x as f64).collect();
+                let (r, shape) = lanes(&v, &self.shape, axis, mean)?;
+                Values::Real(r).write(shape)
+            }
+            Values::Signed(v) => {
+                let v: Vec<_> = v.into_iter().map(|x| x as f64).collect();
+                let (r, shape) = lanes(&v, &self.shape, axis, mean)?;
+                Values::Real(r).write(shape)
+            }
+            Values::Real(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, mean)?;
+                Values::Real(r).write(shape)
+            }
+            Values::Complex(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, mean)?;
+                Values::Complex(r).write(shape)
+            }
+        };
+        Ok(res)
+    }
+    /// Returns the minimum of the elements with the unit. NaN is ignored unless all the elements are NaN.
+    pub fn min(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        let res = match Values::read(self, "min")? {
+            Values::Unsigned(v) => {
+                let (r, shape) =
+                    lanes(&v, &self.shape, axis, |x| Ok(x[position(x, |a, b| a < b)?]))?;
+                Values::Unsigned(r).write(shape)
+            }
+            Values::Signed(v) => {
+                let (r, shape) =
+                    lanes(&v, &self.shape, axis, |x| Ok(x[position(x, |a, b| a < b)?]))?;
+                Values::Signed(r).write(shape)
+            }
+            Values::Real(v) => {
+                let (r, shape) = lanes(&v, &self.shape, axis, |x| Ok(x[position(x, less_real)?]))?;
+                Values::Real(r).write(shape)
+            }
+            Values::Complex(_) => {
+                return Err(ArrayObjectError::UndefinedOperation(
+                    "min",
+                    DataType::Complex,
+                ));
+            }
+        };
+        Ok(ArrayObject {
+            unit: self.unit,
+            ..res
+        })
+    }
+    /// Returns the maximum of the elements with the unit. NaN is ignored unless all the elements are NaN.
+    pub fn max(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        let res = match Values::read(self, "max")? {
+            Values::Unsigned(v) => {
+                let (r, shape) =
+                    lanes(&v, &self.shape, axis, |x| Ok(x[position(x, |a, b| a > b)?]))?;
+                Values::Unsigned(r).write(shape)
+            }
+            Values::Signed(v) => {
+                let (r, shape) =
+                    lanes(&v, &self.shape, axis, |x| Ok(x[position(x, |a, b| a > b)?]))?;
+                Values::Signed(r).write(shape)
+            }
+            Values::Real(v) => {
+                let (r, shape) =
+                    lanes(&v, &self.shape, axis, |x| Ok(x[position(x, greater_real)?]))?;
+                Values::Real(r).write(shape)
+            }
+            Values::Complex(_) => {
+                return Err(ArrayObjectError::UndefinedOperation(
+                    "max",
+                    DataType::Complex,
+                ));
+            }
+        };
+        Ok(ArrayObject {
+            unit: self.unit,
+            ..res
+        })
+    }
+    /// Returns the index of the first maximum as unsigned integers. Without the axis, the index of the flattened array is returned.
+    pub fn argmax(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        let (r, shape) = match Values::read(self, "argmax")? {
+            Values::Unsigned(v) => lanes(&v, &self.shape, axis, |x| position(x, |a, b| a > b))?,
+            Values::Signed(v) => lanes(&v, &self.shape, axis, |x| position(x, |a, b| a > b))?,
+            Values::Real(v) => lanes(&v, &self.shape, axis, |x| position(x, greater_real))?,
+            Values::Complex(_) => {
+                return Err(ArrayObjectError::UndefinedOperation(
+                    "argmax",
+                    DataType::Complex,
+                ));
+            }
+        };
+        Ok(Values::Unsigned(r.into_iter().map(|x| x as u128).collect()).write(shape))
+    }
+    /// Returns the number of non-zero elements as unsigned integers.
+    pub fn count_nonzero(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        fn count<T: Copy>(x: &[T], f: impl Fn(T) -> bool) -> Result<u128, ArrayObjectError> {
+            Ok(x.iter().filter(|v| f(**v)).count() as u128)
+        }
+        let (r, shape) = match Values::read(self, "count_nonzero")? {
+            Values::Unsigned(v) => lanes(&v, &self.shape, axis, |x| count(x, |v| v != 0))?,
+            Values::Signed(v) => lanes(&v, &self.shape, axis, |x| count(x, |v| v != 0))?,
+            Values::Real(v) => lanes(&v, &self.shape, axis, |x| count(x, |v| v != 0.0))?,
+            Values::Complex(v) => lanes(&v, &self.shape, axis, |x| {
+                count(x, |v| v.re != 0.0 || v.im != 0.0)
+            })?,
+        };
+        Ok(Values::Unsigned(r).write(shape))
+    }
+    /// Returns the number of NaN as unsigned integers. A complex number is NaN if either part is NaN.
+    pub fn count_nan(&self, axis: Option<usize>) -> Result<ArrayObject, ArrayObjectError> {
+        let (r, shape) = match Values::read(self, "count_nan")? {
+            Values::Real(v) => lanes(&v, &self.shape, axis, |x| {
+                Ok(x.iter().filter(|v| v.is_nan()).count() as u128)
+            })?,
+            Values::Complex(v) => lanes(&v, &self.shape, axis, |x| {
+                Ok(x.iter().filter(|v| v.is_nan()).count() as u128)
+            })?,
+            Values::Unsigned(v) => lanes(&v, &self.shape, axis, |_| Ok(0))?,
+            Values::Signed(v) => lanes(&v, &self.shape, axis, |_| Ok(0))?,
+        };
+        Ok(Values::Unsigned(r).write(shape))
+    }
+    /// Adds two arrays elementwise. The shapes should agree unless one of them is a scalar, and the units should agree.
+    pub fn add(&self, other: &ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+        self.elementwise(other, "add", Operator::Add)
+    }
+    /// Subtracts two arrays elementwise. The shapes should agree unless one of them is a scalar, and the units should agree.
+    /// The difference of unsigned integers is signed if any of the elements is negative.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let a: ArrayObject = vec![1u8, 5].into();
+    /// let diff: Vec<i16> = a.sub(&3u8.into()).unwrap().try_into().unwrap();
+    /// assert_eq!(diff, vec![-2, 2]);
+    /// ```
+    pub fn sub(&self, other: &ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+        self.elementwise(other, "sub", Operator::Sub)
+    }
+    /// Multiplies two arrays elementwise. The shapes should agree unless one of them is a scalar.
+    /// Decimal numbers and durations multiplied by plain numbers keep the unit, and the exponents of two decimal numbers are added.
+    pub fn mul(&self, other: &ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+        self.elementwise(other, "mul", Operator::Mul)
+    }
+    /// Multiplies all the elements by a scalar.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1u8, 2, 3].into();
+    /// let scaled: Vec<f64> = obj.scale(0.5).unwrap().try_into().unwrap();
+    /// assert_eq!(scaled, vec![0.5, 1.0, 1.5]);
+    /// ```
+    pub fn scale<T: Into<ArrayObject>>(&self, factor: T) -> Result<ArrayObject, ArrayObjectError> {
+        let factor: ArrayObject = factor.into();
+        if !factor.shape.is_empty() {
+            return Err(ArrayObjectError::ShapeMismatch(vec![], factor.shape()));
+        }
+        self.mul(&factor)
+    }
+    fn elementwise(
+        &self,
+        other: &ArrayObject,
+        op: &'static str,
+        operator: Operator,
+    ) -> Result<ArrayObject, ArrayObjectError> {
+        let shape = if self.shape == other.shape || other.shape.is_empty() {
+            self.shape.clone()
+        } else if self.shape.is_empty() {
+            other.shape.clone()
+        } else {
+            return Err(ArrayObjectError::ShapeMismatch(self.shape(), other.shape()));
+        };
+        let datatype = common_datatype(&self.datatype, &other.datatype);
+        let lhs = self.clone().cast(datatype.clone(), CastOption::Checked)?;
+        let rhs = other.clone().cast(datatype, CastOption::Checked)?;
+        let unit = operator.unit(op, &lhs.datatype, lhs.unit, rhs.unit)?;
+        let (lhs, rhs) = (Values::read(&lhs, op)?, Values::read(&rhs, op)?);
+        let len = shape.product() as usize;
+        let signed = |a: &[i128], b: &[i128]| {
+            apply(a, b, len, |x, y| {
+                match operator {
+                    Operator::Add => x.checked_add(y),
+                    Operator::Sub => x.checked_sub(y),
+                    Operator::Mul => x.checked_mul(y),
+                }
+                .ok_or(ArrayObjectError::Overflow)
+            })
+        };
+        let res = match (lhs, rhs) {
+            // The difference of unsigned integers is signed if any of the elements is negative.
+            (Values::Unsigned(a), Values::Unsigned(b))
+                if matches!(operator, Operator::Sub)
+                    && (0..len).any(|i| a[i % a.len()] < b[i % b.len()]) =>
+            {
+                let to_signed = |v: Vec<u128>| {
+                    v.into_iter()
+                        .map(|x| i128::try_from(x).or(Err(ArrayObjectError::Overflow)))
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                Values::Signed(signed(&to_signed(a)?, &to_signed(b)?)?)
+            }
+            (Values::Unsigned(a), Values::Unsigned(b)) => {
+                Values::Unsigned(apply(&a, &b, len, |x, y| {
+                    match operator {
+                        Operator::Add => x.checked_add(y),
+                        Operator::Sub => x.checked_sub(y),
+                        Operator::Mul => x.checked_mul(y),
+                    }
+                    .ok_or(ArrayObjectError::Overflow)
+                })?)
+            }
+            (Values::Signed(a), Values::Signed(b)) => Values::Signed(signed(&a, &b)?),
+            (Values::Real(a), Values::Real(b)) => {
+                Values::Real(apply(&a, &b, len, |x, y| Ok(operator.apply(x, y)))?)
+            }
+            (Values::Complex(a), Values::Complex(b)) => {
+                Values::Complex(apply(&a, &b, len, |x, y| Ok(operator.apply(x, y)))?)
+            }
+            _ => unreachable!(),
+        };
+        Ok(ArrayObject {
+            unit,
+            ..res.write(shape)
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Operator {
+    /// Returns the unit of the result. The units of the sum and the difference should agree.
+    /// Points in time are the exception: the difference of two timestamps is a duration of the same resolution,
+    /// a duration may be added to or subtracted from a timestamp, and the difference of two dates is a number of days.
+    /// A product keeps the unit of decimal numbers or durations multiplied by plain numbers, and adds the exponents of two decimal numbers.
+    fn unit(
+        self,
+        op: &'static str,
+        datatype: &DataType,
+        lhs: Option<Unit>,
+        rhs: Option<Unit>,
+    ) -> Result<Option<Unit>, ArrayObjectError> {
+        match (self, lhs, rhs) {
+            (
+                Operator::Add,
+                Some(Unit::Timestamp(_) | Unit::Date),
+                Some(Unit::Timestamp(_) | Unit::Date),
+            ) => Err(ArrayObjectError::UndefinedOperation(op, datatype.clone())),
+            (Operator::Sub, Some(Unit::Timestamp(a)), Some(Unit::Timestamp(b))) if a == b => {
+                Ok(Some(Unit::Duration(a)))
+            }
+            (Operator::Sub, Some(Unit::Date), Some(Unit::Date)) => Ok(None),
+            (Operator::Add | Operator::Sub, Some(Unit::Timestamp(a)), Some(Unit::Duration(b)))
+            | (Operator::Add, Some(Unit::Duration(b)), Some(Unit::Timestamp(a)))
+                if a == b =>
+            {
+                Ok(Some(Unit::Timestamp(a)))
+            }
+            (Operator::Add | Operator::Sub, lhs, rhs) if lhs == rhs => Ok(lhs),
+            (Operator::Add | Operator::Sub, Some(lhs), rhs) => {
+                Err(ArrayObjectError::WrongUnit(rhs, lhs))
+            }
+            (Operator::Add | Operator::Sub, None, Some(rhs)) => {
+                Err(ArrayObjectError::WrongUnit(None, rhs))
+            }
+            (Operator::Mul, Some(Unit::Decimal(a)), Some(Unit::Decimal(b))) => Ok(Some(
+                Unit::Decimal(a.checked_add(b).ok_or(ArrayObjectError::Overflow)?),
+            )),
+            (Operator::Mul, Some(x @ (Unit::Decimal(_) | Unit::Duration(_))), None)
+            | (Operator::Mul, None, Some(x @ (Unit::Decimal(_) | Unit::Duration(_)))) => {
+                Ok(Some(x))
+            }
+            _ => Ok(None),
+        }
+    }
+    fn apply<
+        T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+    >(
+        self,
+        x: T,
+        y: T,
+    ) -> T {
+        match self {
+            Operator::Add => x + y,
+            Operator::Sub => x - y,
+            Operator::Mul => x * y,
+        }
+    }
+}
+
+/// Applies the function elementwise. A scalar is broadcasted to the length.
+fn apply<T: Copy>(
+    a: &[T],
+    b: &[T],
+    len: usize,
+    f: impl Fn(T, T) -> Result<T, ArrayObjectError>,
+) -> Result<Vec<T>, ArrayObjectError> {
+    (0..len)
+        .map(|i| f(a[i % a.len()], b[i % b.len()]))
+        .collect()
+}
@@ -0,0 +1,263 @@
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::adaptor::VecShape;
+use crate::error::ArrayObjectError;
+use crate::pack::{Pack, Unpack};
+use crate::storage::ArrayObject;
+
+/// The marker at the end of a container file.
+const MAGIC: &[u8; 8] = b"AOBJLIST";
+
+/// The size of the trailer: the lengths of the packed names and positions, and the marker.
+const TRAILER: u64 = 24;
+
+/// A single file holding multiple named objects.
+///
+/// The file consists of the packed objects followed by the index, which is the names and the positions of the objects stored as ArrayObjects.
+/// Each object is read only when it is requested, and a new object is appended without rewriting the existing ones.
+/// The objects are read with positional reads, so a container can be shared between threads.
+///
+/// ```
+/// use array_object::*;
+/// let path = std::env::temp_dir().join("container.bin");
+/// let mut container = Container::create(&path).unwrap();
+/// container.append("x", vec![1.0f64, 2.0].into()).unwrap();
+/// container.append("label", "first".to_string().into()).unwrap();
+///
+/// let container = Container::open(&path).unwrap();
+/// assert_eq!(container.names(), &["x", "label"]);
+/// let label: String = container.read_as("label").unwrap();
+/// assert_eq!(label, "first");
+/// ```
+pub struct Container {
+    file: File,
+    names: Vec<String>,
+    positions: Vec<(u64, u64)>,
+    index_start: u64,
+}
+
+impl Container {
+    /// Creates an empty container. If the file exists, this will overwrite the existing file.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, ArrayObjectError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        let mut container = Container {
+            file,
+            names: vec![],
+            positions: vec![],
+            index_start: 0,
+        };
+        container.write_index()?;
+        Ok(container)
+    }
+    /// Opens a container and reads the index. The file is opened read only if it is not writable, in which case `append` fails.
+    ///
+    /// If the end of the file is not a valid index, as left by an interrupted `append`, the last valid index before it is used.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ArrayObjectError> {
+        let path = path.as_ref();
+        let file = match OpenOptions::new().read(true).write(true).open(path) {
+            Err(err) if err.kind() == ErrorKind::PermissionDenied => File::open(path)?,
+            file => file?,
+        };
+        let len = file.metadata()?.len();
+        let (names, positions, index_start) = last_index(&file, len)?;
+        Ok(Container {
+            file,
+            names,
+            positions,
+            index_start,
+        })
+    }
+    /// Returns the names of the objects in the order they were appended.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+    /// Returns the number of the objects.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+    /// Returns true if the container has no object.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+    /// Returns true if the container has an object of the name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|x| x == name)
+    }
+    /// Reads only the object of the name from the file.
+    pub fn read(&self, name: &str) -> Result<ArrayObject, ArrayObjectError> {
+        let i = self
+            .names
+            .iter()
+            .position(|x| x == name)
+            .ok_or_else(|| ArrayObjectError::NameNotFound(name.to_string()))?;
+        let (offset, len) = self.positions[i];
+        ArrayObject::unpack(read_at(&self.file, offset, len)?)
+    }
+    /// Reads the object of the name and restores the data into `T`.
+    pub fn read_as<T>(&self, name: &str) -> Result<T, ArrayObjectError>
+    where
+        T: TryFrom<ArrayObject>,
+        ArrayObjectError: From<T::Error>,
+    {
+        Ok(self.read(name)?.try_into()?)
+    }
+    /// Appends the object with the name. The existing objects are never rewritten.
+    ///
+    /// The object and the new index are first written after the end of the file and flushed, so that `open` finds either the old or the new index if the writing is interrupted.
+    /// Then they are moved over the old index and the staged copy is truncated.
+    pub fn append(&mut self, name: &str, obj: ArrayObject) -> Result<(), ArrayObjectError> {
+        if self.contains(name) {
+            return Err(ArrayObjectError::DuplicateName(name.to_string()));
+        }
+        let data = obj.pack();
+        self.names.push(name.to_string());
+        if let Err(err) = self.write_object(&data) {
+            self.names.pop();
+            return Err(err);
+        }
+        self.positions.push((self.index_start, data.len() as u64));
+        self.index_start += data.len() as u64;
+        Ok(())
+    }
+    fn write_object(&mut self, data: &[u8]) -> Result<(), ArrayObjectError> {
+        let len = data.len() as u64;
+        let compact = [data, &self.index(Some((self.index_start, len)))?].concat();
+        // The staged copy starts after both the current end of the file and the compact copy, so that moving it never overwrites itself.
+        let staging = self
+            .file
+            .metadata()?
+            .len()
+            .max(self.index_start + compact.len() as u64);
+        let staged = [data, &self.index(Some((staging, len)))?].concat();
+        self.write_at(staging, &staged)?;
+        self.file.sync_all()?;
+        self.write_at(self.index_start, &compact)?;
+        self.file.sync_all()?;
+        self.file.set_len(self.index_start + compact.len() as u64)?;
+        self.file.sync_all()?;
+        Ok(())
+    }
+    /// Writes the index and the trailer after the objects and flushes the file to the disk.
+    fn write_index(&mut self) -> Result<(), ArrayObjectError> {
+        let index = self.index(None)?;
+        self.write_at(self.index_start, &index)?;
+        self.file.set_len(self.index_start + index.len() as u64)?;
+        self.file.sync_all()?;
+        Ok(())
+    }
+    /// Returns the packed index and the trailer, with the position of the new object if any.
+    fn index(&self, new: Option<(u64, u64)>) -> Result<Vec<u8>, ArrayObjectError> {
+        let names: ArrayObject = self.names.clone().into();
+        let positions: Vec<u64> = self
+            .positions
+            .iter()
+            .chain(&new)
+            .flat_map(|&(o, l)| [o, l])
+            .collect();
+        let positions: ArrayObject =
+            VecShape(positions, vec![self.names.len() as u64, 2]).try_into()?;
+        let (names, positions) = (names.pack(), positions.pack());
+        let trailer = [
+            (names.len() as u64).to_le_bytes(),
+            (positions.len() as u64).to_le_bytes(),
+            *MAGIC,
+        ]
+        .concat();
+        Ok([names, positions, trailer].concat())
+    }
+    fn write_at(&mut self, offset: u64, data: &[u8]) -> Result<(), ArrayObjectError> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(data)?;
+        Ok(())
+    }
+}
+
+/// The names, the positions of the objects and the start of the index.
+type Index = (Vec<String>, Vec<(u64, u64)>, u64);
+
+/// The size of the blocks read while searching back for a trailer.
+const SEARCH_BLOCK: u64 = 1 << 16;
+
+/// Returns the last valid index that ends at or before `len`, searching back for the marker if the end of the file is broken.
+fn last_index(file: &File, len: u64) -> Result<Index, ArrayObjectError> {
+    let mut end = len;
+    loop {
+        let start = end.saturating_sub(SEARCH_BLOCK);
+        let block = read_at(file, start, end - start)?;
+        for i in (0..block.len().saturating_sub(MAGIC.len() - 1)).rev() {
+            if &block[i..i + MAGIC.len()] == MAGIC
+                && let Ok(index) = read_index(file, start + (i + MAGIC.len()) as u64)
+            {
+                return Ok(index);
+            }
+        }
+        if start == 0 {
+            return Err(ArrayObjectError::UnableToDecode);
+        }
+        // The blocks overlap so that a marker across the boundary is found.
+        end = start + MAGIC.len() as u64 - 1;
+    }
+}
+
+/// Reads the index whose trailer ends at `end`.
+fn read_index(file: &File, end: u64) -> Result<Index, ArrayObjectError> {
+    let trailer_start = end
+        .checked_sub(TRAILER)
+        .ok_or(ArrayObjectError::UnableToDecode)?;
+    let trailer = read_at(file, trailer_start, TRAILER)?;
+    if &trailer[16..] != MAGIC {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let names_len = u64::from_le_bytes(trailer[..8].try_into().unwrap());
+    let positions_len = u64::from_le_bytes(trailer[8..16].try_into().unwrap());
+    let index_start = names_len
+        .checked_add(positions_len)
+        .and_then(|x| trailer_start.checked_sub(x))
+        .ok_or(ArrayObjectError::UnableToDecode)?;
+    let names: Vec<String> =
+        ArrayObject::unpack(read_at(file, index_start, names_len)?)?.try_into()?;
+    let VecShape::<u64>(positions, shape) =
+        ArrayObject::unpack(read_at(file, index_start + names_len, positions_len)?)?.try_into()?;
+    if shape != [names.len() as u64, 2] {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let positions: Vec<_> = positions.chunks(2).map(|x| (x[0], x[1])).collect();
+    if positions
+        .iter()
+        .any(|&(offset, len)| offset.checked_add(len).is_none_or(|end| end > index_start))
+    {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    Ok((names, positions, index_start))
+}
+
+/// Reads `len` bytes from the offset without moving the cursor of the file, so that the objects can be read concurrently.
+#[cfg(unix)]
+fn read_at(file: &File, offset: u64, len: u64) -> Result<Vec<u8>, ArrayObjectError> {
+    use std::os::unix::fs::FileExt;
+    let mut data = vec![0; len as usize];
+    file.read_exact_at(&mut data, offset)?;
+    Ok(data)
+}
+
+/// Reads `len` bytes from the offset without moving the cursor of the file, so that the objects can be read concurrently.
+#[cfg(windows)]
+fn read_at(file: &File, offset: u64, len: u64) -> Result<Vec<u8>, ArrayObjectError> {
+    use std::os::windows::fs::FileExt;
+    let mut data = vec![0; len as usize];
+    let mut filled = 0;
+    while filled < data.len() {
+        match file.seek_read(&mut data[filled..], offset + filled as u64)? {
+            0 => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+            n => filled += n,
+        }
+    }
+    Ok(data)
+}
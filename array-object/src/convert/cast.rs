@@ -0,0 +1,362 @@
+use crate::convert::from_bytes::join_bytes;
+use crate::convert::zigzag::Zigzag;
+use crate::error::ArrayObjectError;
+use crate::storage::*;
+
+/// Options for `ArrayObject::cast()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CastOption {
+    /// Demotions are allowed only when all the elements are exactly representable in the new type.
+    #[default]
+    Checked,
+    /// Demotions are always allowed. Floats are truncated towards zero, integers are saturated and the imaginary part is dropped.
+    Lossy,
+}
+
+impl ArrayObject {
+    /// Converts the data into another data type along the hierarchy of integer ⊂ real ⊂ complex.
+    /// Strings and bytes are converted into each other, checking that the bytes are valid UTF-8.
+    /// Decimal numbers are scaled by the exponent when converted into another data type.
+    ///
+    /// Promotions, e.g. integer to real or real to complex, fail only when the precision of 64bit float is not enough.
+    /// Demotions, e.g. complex to real or real to integer, are checked unless `CastOption::Lossy` is given.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1u8, 2, 3].into();
+    /// let obj = obj.cast(DataType::Complex, CastOption::Checked).unwrap();
+    /// let obj = obj.cast(DataType::SignedInteger, CastOption::Checked).unwrap();
+    /// let restored: Vec<i32> = obj.try_into().unwrap();
+    /// assert_eq!(restored, vec![1, 2, 3]);
+    /// ```
+    pub fn cast(
+        mut self,
+        datatype: DataType,
+        option: CastOption,
+    ) -> Result<Self, ArrayObjectError> {
+        let lossy = option == CastOption::Lossy;
+        if self.datatype != datatype {
+            self = self.descale(
+                matches!(datatype, DataType::Real | DataType::Complex),
+                lossy,
+            )?;
+        }
+        let validity = self.validity.take();
+        let unit = self.unit.take().filter(|_| self.datatype == datatype);
+        if self.datatype != datatype {
+            self = narrow_integer(self)?;
+        }
+        let mut res = match (&self.datatype, &datatype) {
+            (from, to) if from == to => Ok(self),
+            (DataType::UnsignedInteger, DataType::SignedInteger) => unsigned_to_signed(self, lossy),
+            (DataType::SignedInteger, DataType::UnsignedInteger) => signed_to_unsigned(self, lossy),
+            (DataType::UnsignedInteger | DataType::SignedInteger, DataType::Real) => {
+                integer_to_real(self, lossy)
+            }
+            (DataType::UnsignedInteger | DataType::SignedInteger, DataType::Complex) => {
+                real_to_complex(integer_to_real(self, lossy)?)
+            }
+            (DataType::Real, DataType::Complex) => real_to_complex(self),
+            (DataType::Complex, DataType::Real) => complex_to_real(self, lossy),
+            (DataType::Real, DataType::SignedInteger) => real_to_signed(self, lossy),
+            (DataType::Real, DataType::UnsignedInteger) => {
+                signed_to_unsigned(real_to_signed(self, lossy)?, lossy)
+            }
+            (DataType::Complex, DataType::SignedInteger) => {
+                real_to_signed(complex_to_real(self, lossy)?, lossy)
+            }
+            (DataType::Complex, DataType::UnsignedInteger) => {
+                signed_to_unsigned(real_to_signed(complex_to_real(self, lossy)?, lossy)?, lossy)
+            }
+            (DataType::String, DataType::Bytes) => string_to_bytes(self),
+            (DataType::Bytes, DataType::String) => bytes_to_string(self),
+            (from, to) => Err(ArrayObjectError::UnsupportedCast(from.clone(), to.clone())),
+        }?;
+        res.validity = validity;
+        res.unit = unit;
+        Ok(res)
+    }
+}
+
+impl ArrayObject {
+    /// Promotes integers into real numbers, or integers and real numbers into complex numbers. Otherwise, returns the data as it is.
+    pub(crate) fn promote(self, datatype: DataType) -> Result<Self, ArrayObjectError> {
+        match (&self.datatype, &datatype) {
+            (
+                DataType::UnsignedInteger | DataType::SignedInteger,
+                DataType::Real | DataType::Complex,
+            )
+            | (DataType::Real, DataType::Complex) => self.cast(datatype, CastOption::Checked),
+            _ => Ok(self),
+        }
+    }
+}
+
+fn string_to_bytes(obj: ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+    let data = join_bytes(obj.elements().unwrap_or_default());
+    Ok(ArrayObject {
+        data,
+        shape: obj.shape,
+        datatype: DataType::Bytes,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn bytes_to_string(obj: ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+    let elements = obj.elements().ok_or(ArrayObjectError::UnableToDecode)?;
+    for (i, x) in elements.iter().enumerate() {
+        if std::str::from_utf8(x).is_err() {
+            return Err(ArrayObjectError::InvalidString(i));
+        }
+    }
+    let data = elements.join(&255u8);
+    Ok(ArrayObject {
+        data,
+        shape: obj.shape,
+        datatype: DataType::String,
+        validity: None,
+        unit: None,
+    })
+}
+
+/// Returns true if the integers are stored in more than 128 bits.
+pub(crate) fn is_wide(obj: &ArrayObject) -> bool {
+    matches!(
+        obj.datatype,
+        DataType::UnsignedInteger | DataType::SignedInteger
+    ) && element_size(obj) > 16
+}
+
+/// Narrows integers stored in more than 128 bits into 128 bits, or returns the number of bits required.
+pub(crate) fn narrow_integer(mut obj: ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+    if !is_wide(&obj) {
+        return Ok(obj);
+    }
+    let size = element_size(&obj);
+    let min_size = obj
+        .data
+        .chunks(size)
+        .map(|x| size - x.iter().rev().position(|i| *i > 0).unwrap_or(size - 1))
+        .max()
+        .unwrap_or(1);
+    if min_size > 16 {
+        return Err(ArrayObjectError::IncompatibleConversion(
+            obj.datatype == DataType::SignedInteger,
+            8 * min_size.next_power_of_two(),
+        ));
+    }
+    obj.data = obj
+        .data
+        .chunks(size)
+        .flat_map(|x| x[..16].to_vec())
+        .collect();
+    Ok(obj)
+}
+
+/// Returns the size of each element in bytes. For complex numbers, the size of each part is returned.
+fn element_size(obj: &ArrayObject) -> usize {
+    match (obj.len(), &obj.datatype) {
+        (0, _) => 0,
+        (len, DataType::Complex) => obj.data.len() / len / 2,
+        (len, _) => obj.data.len() / len,
+    }
+}
+
+pub(crate) fn read_unsigned(obj: &ArrayObject) -> Vec<u128> {
+    let size = element_size(obj);
+    if size == 0 {
+        return vec![];
+    }
+    obj.data
+        .chunks(size)
+        .map(|x| {
+            let mut bytes = [0u8; 16];
+            bytes[..size].copy_from_slice(x);
+            u128::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+pub(crate) fn read_signed(obj: &ArrayObject) -> Vec<i128> {
+    read_unsigned(obj)
+        .into_iter()
+        .map(|x| (x as i128).straight())
+        .collect()
+}
+
+pub(crate) fn read_float(obj: &ArrayObject) -> Vec<f64> {
+    match element_size(obj) {
+        4 => obj
+            .data
+            .chunks(4)
+            .map(|x| f32::from_le_bytes(x.try_into().unwrap()) as f64)
+            .collect(),
+        8 => obj
+            .data
+            .chunks(8)
+            .map(|x| f64::from_le_bytes(x.try_into().unwrap()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Stores unsigned integers with the smallest size of the integer type.
+pub(crate) fn write_unsigned(val: Vec<u128>) -> Vec<u8> {
+    let size = match val.iter().max().map_or(0, |x| 128 - x.leading_zeros()) {
+        0..=8 => 1,
+        9..=16 => 2,
+        17..=32 => 4,
+        33..=64 => 8,
+        _ => 16,
+    };
+    val.into_iter()
+        .flat_map(|x| x.to_le_bytes().into_iter().take(size))
+        .collect()
+}
+
+pub(crate) fn write_signed(val: Vec<i128>) -> Vec<u8> {
+    write_unsigned(val.into_iter().map(|x| x.zigzag() as u128).collect())
+}
+
+/// Stores floats in 32bit if it is lossless, and in 64bit otherwise.
+pub(crate) fn write_float(val: Vec<f64>) -> Vec<u8> {
+    if val
+        .iter()
+        .all(|x| ((*x as f32) as f64).to_bits() == x.to_bits())
+    {
+        val.into_iter()
+            .flat_map(|x| (x as f32).to_le_bytes())
+            .collect()
+    } else {
+        val.into_iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+}
+
+fn unsigned_to_signed(obj: ArrayObject, lossy: bool) -> Result<ArrayObject, ArrayObjectError> {
+    let mut val = vec![];
+    for x in read_unsigned(&obj) {
+        if x > i128::MAX as u128 && !lossy {
+            return Err(ArrayObjectError::IncompatibleConversion(false, 128));
+        }
+        val.push(x.min(i128::MAX as u128) as i128);
+    }
+    Ok(ArrayObject {
+        data: write_signed(val),
+        shape: obj.shape,
+        datatype: DataType::SignedInteger,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn signed_to_unsigned(obj: ArrayObject, lossy: bool) -> Result<ArrayObject, ArrayObjectError> {
+    let val = read_signed(&obj);
+    if !lossy && val.iter().any(|x| *x < 0) {
+        let size = write_signed(val).len() / obj.len();
+        return Err(ArrayObjectError::IncompatibleConversion(true, 8 * size));
+    }
+    Ok(ArrayObject {
+        data: write_unsigned(val.into_iter().map(|x| x.max(0) as u128).collect()),
+        shape: obj.shape,
+        datatype: DataType::UnsignedInteger,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn integer_to_real(obj: ArrayObject, lossy: bool) -> Result<ArrayObject, ArrayObjectError> {
+    let val: Vec<f64> = if obj.datatype == DataType::UnsignedInteger {
+        let val = read_unsigned(&obj);
+        if !lossy && val.iter().any(|x| (*x as f64) as u128 != *x) {
+            return Err(ArrayObjectError::LossyConversion);
+        }
+        val.into_iter().map(|x| x as f64).collect()
+    } else {
+        let val = read_signed(&obj);
+        if !lossy && val.iter().any(|x| (*x as f64) as i128 != *x) {
+            return Err(ArrayObjectError::LossyConversion);
+        }
+        val.into_iter().map(|x| x as f64).collect()
+    };
+    Ok(ArrayObject {
+        data: write_float(val),
+        shape: obj.shape,
+        datatype: DataType::Real,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn real_to_complex(obj: ArrayObject) -> Result<ArrayObject, ArrayObjectError> {
+    let size = element_size(&obj);
+    let data = if size == 0 {
+        vec![]
+    } else {
+        obj.data
+            .chunks(size)
+            .flat_map(|x| x.iter().copied().chain(std::iter::repeat_n(0, size)))
+            .collect()
+    };
+    Ok(ArrayObject {
+        data,
+        shape: obj.shape,
+        datatype: DataType::Complex,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn complex_to_real(obj: ArrayObject, lossy: bool) -> Result<ArrayObject, ArrayObjectError> {
+    let size = element_size(&obj);
+    if size == 0 {
+        return Ok(ArrayObject {
+            data: vec![],
+            shape: obj.shape,
+            datatype: DataType::Real,
+            validity: None,
+            unit: None,
+        });
+    }
+    if !lossy
+        && read_float(&obj)
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .any(|x| *x != 0.0)
+    {
+        return Err(ArrayObjectError::LossyConversion);
+    }
+    let data = obj
+        .data
+        .chunks(2 * size)
+        .flat_map(|x| x[..size].to_vec())
+        .collect();
+    Ok(ArrayObject {
+        data,
+        shape: obj.shape,
+        datatype: DataType::Real,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn real_to_signed(obj: ArrayObject, lossy: bool) -> Result<ArrayObject, ArrayObjectError> {
+    let val = read_float(&obj);
+    // 2^127 saturates to i128::MAX, which rounds back to 2^127, so the range is checked explicitly. NaN fails the comparisons.
+    let limit = 2f64.powi(127);
+    if !lossy
+        && val
+            .iter()
+            .any(|x| x.fract() != 0.0 || !(*x >= -limit && *x < limit))
+    {
+        return Err(ArrayObjectError::LossyConversion);
+    }
+    Ok(ArrayObject {
+        data: write_signed(val.into_iter().map(|x| x as i128).collect()),
+        shape: obj.shape,
+        datatype: DataType::SignedInteger,
+        validity: None,
+        unit: None,
+    })
+}
@@ -0,0 +1,27 @@
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::storage::*;
+
+impl TryFrom<AnyArray> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(val: AnyArray) -> Result<Self, Self::Error> {
+        match val {
+            AnyArray::U8(v) => v.try_into(),
+            AnyArray::U16(v) => v.try_into(),
+            AnyArray::U32(v) => v.try_into(),
+            AnyArray::U64(v) => v.try_into(),
+            AnyArray::U128(v) => v.try_into(),
+            AnyArray::I8(v) => v.try_into(),
+            AnyArray::I16(v) => v.try_into(),
+            AnyArray::I32(v) => v.try_into(),
+            AnyArray::I64(v) => v.try_into(),
+            AnyArray::I128(v) => v.try_into(),
+            AnyArray::F32(v) => v.try_into(),
+            AnyArray::F64(v) => v.try_into(),
+            AnyArray::C32(v) => v.try_into(),
+            AnyArray::C64(v) => v.try_into(),
+            AnyArray::Str(v) => v.try_into(),
+            AnyArray::Bytes(v) => v.try_into(),
+        }
+    }
+}
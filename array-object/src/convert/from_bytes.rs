@@ -0,0 +1,70 @@
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::pack::varint::varint_encode;
+use crate::storage::*;
+
+/// Concatenates the byte strings, each prefixed by its length as a variable integer.
+pub(crate) fn join_bytes<T: AsRef<[u8]>>(val: impl IntoIterator<Item = T>) -> Vec<u8> {
+    let mut data = vec![];
+    for v in val {
+        let v = v.as_ref();
+        data.append(&mut varint_encode(vec![v.len() as u64]));
+        data.extend_from_slice(v);
+    }
+    data
+}
+
+impl From<Vec<Vec<u8>>> for ArrayObject {
+    fn from(val: Vec<Vec<u8>>) -> Self {
+        let shape = vec![val.len() as u64];
+        Self {
+            data: join_bytes(val),
+            shape,
+            datatype: DataType::Bytes,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+impl From<&Vec<Vec<u8>>> for ArrayObject {
+    fn from(val: &Vec<Vec<u8>>) -> Self {
+        let shape = vec![val.len() as u64];
+        Self {
+            data: join_bytes(val),
+            shape,
+            datatype: DataType::Bytes,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+impl From<&[Vec<u8>]> for ArrayObject {
+    fn from(val: &[Vec<u8>]) -> Self {
+        let shape = vec![val.len() as u64];
+        Self {
+            data: join_bytes(val),
+            shape,
+            datatype: DataType::Bytes,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+impl TryFrom<VecShape<Vec<u8>>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(VecShape(val, shape): VecShape<Vec<u8>>) -> Result<Self, Self::Error> {
+        if val.len() != shape.product() as usize {
+            return Err(ArrayObjectError::NumberOfElementsMismatch(
+                val.len(),
+                shape.product() as usize,
+            ));
+        }
+        let mut temp: ArrayObject = val.into();
+        temp.shape = shape;
+        Ok(temp)
+    }
+}
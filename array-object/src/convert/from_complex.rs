@@ -14,6 +14,8 @@ macro_rules! from_complex {
                         data,
                         shape: vec![],
                         datatype: DataType::Complex,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -50,6 +52,8 @@ macro_rules! from_complex {
                         data,
                         shape,
                         datatype: DataType::Complex,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -65,6 +69,8 @@ macro_rules! from_complex {
                         data,
                         shape,
                         datatype: DataType::Complex,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -80,6 +86,8 @@ macro_rules! from_complex {
                         data,
                         shape,
                         datatype: DataType::Complex,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -99,6 +107,8 @@ macro_rules! from_complex {
                         data,
                         shape,
                         datatype: DataType::Complex,
+                        validity: None,
+                        unit: None,
                     })
                 }
             }
@@ -108,9 +118,6 @@ macro_rules! from_complex {
                     if val.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = val.into();
                     temp.shape = shape;
                     Ok(temp)
@@ -122,9 +129,6 @@ macro_rules! from_complex {
                     if val_re.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val_re.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = VecVec(val_re, val_im).try_into()?;
                     temp.shape = shape;
                     Ok(temp)
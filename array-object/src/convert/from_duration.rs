@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::*;
+
+time_impl!(
+    Duration,
+    Unit::Duration(TimeUnit::Nanosecond),
+    |x: &Duration| x.as_nanos() as i128,
+    |x: i128| {
+        let x = u128::try_from(x).ok()?;
+        let secs = u64::try_from(x / 1_000_000_000).ok()?;
+        Some(Duration::new(secs, (x % 1_000_000_000) as u32))
+    }
+);
@@ -14,6 +14,8 @@ macro_rules! from_unsigned_integer {
                         data,
                         shape: vec![],
                         datatype: DataType::UnsignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -24,6 +26,8 @@ macro_rules! from_unsigned_integer {
                         data,
                         shape: vec![],
                         datatype: DataType::UnsignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -38,6 +42,8 @@ macro_rules! from_unsigned_integer {
                         data,
                         shape,
                         datatype: DataType::UnsignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -52,6 +58,8 @@ macro_rules! from_unsigned_integer {
                         data,
                         shape,
                         datatype: DataType::UnsignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -71,9 +79,6 @@ macro_rules! from_unsigned_integer {
                     if val.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = val.into();
                     temp.shape = shape;
                     Ok(temp)
@@ -95,6 +100,8 @@ macro_rules! from_signed_integer {
                         data,
                         shape: vec![],
                         datatype: DataType::SignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -109,6 +116,8 @@ macro_rules! from_signed_integer {
                         data,
                         shape,
                         datatype: DataType::SignedInteger,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -118,9 +127,6 @@ macro_rules! from_signed_integer {
                     if val.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = val.into();
                     temp.shape = shape;
                     Ok(temp)
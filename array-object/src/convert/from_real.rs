@@ -13,6 +13,8 @@ macro_rules! from_float {
                         data,
                         shape: vec![],
                         datatype: DataType::Real,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -23,6 +25,8 @@ macro_rules! from_float {
                         data,
                         shape: vec![],
                         datatype: DataType::Real,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -37,6 +41,8 @@ macro_rules! from_float {
                         data,
                         shape,
                         datatype: DataType::Real,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -51,6 +57,8 @@ macro_rules! from_float {
                         data,
                         shape,
                         datatype: DataType::Real,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -70,9 +78,6 @@ macro_rules! from_float {
                     if val.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = val.into();
                     temp.shape = shape;
                     Ok(temp)
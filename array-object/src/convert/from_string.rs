@@ -2,6 +2,7 @@ use crate::adaptor::*;
 use crate::error::ArrayObjectError;
 use crate::misc::Product;
 use crate::storage::*;
+use std::ffi::OsString;
 
 macro_rules! from_text {
     ($($ty:ty),*) => {
@@ -13,6 +14,8 @@ macro_rules! from_text {
                         data,
                         shape: vec![],
                         datatype: DataType::String,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -23,6 +26,8 @@ macro_rules! from_text {
                         data,
                         shape: vec![],
                         datatype: DataType::String,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -35,6 +40,8 @@ macro_rules! from_text {
                         data,
                         shape,
                         datatype: DataType::String,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -47,6 +54,8 @@ macro_rules! from_text {
                         data,
                         shape,
                         datatype: DataType::String,
+                        validity: None,
+                        unit: None,
                     }
                 }
             }
@@ -66,9 +75,6 @@ macro_rules! from_text {
                     if val.len() != shape.product() as usize {
                         return Err(ArrayObjectError::NumberOfElementsMismatch(val.len(), shape.product() as usize));
                     }
-                    if shape.len() > 15 {
-                        return Err(ArrayObjectError::TooLargeDimension(shape.len()));
-                    }
                     let mut temp: ArrayObject = val.into();
                     temp.shape = shape;
                     Ok(temp)
@@ -79,3 +85,62 @@ macro_rules! from_text {
 }
 
 from_text!(String, &str);
+
+impl ArrayObject {
+    /// Creates a 1D array of strings from raw bytes, checking that each element is valid UTF-8. Use `Vec<Vec<u8>>` to store arbitrary bytes instead.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj = ArrayObject::from_utf8(vec![b"abc".to_vec(), vec![]]).unwrap();
+    /// assert_eq!(obj.datatype(), DataType::String);
+    /// assert!(ArrayObject::from_utf8(vec![vec![0xFF]]).is_err());
+    /// ```
+    pub fn from_utf8(val: Vec<Vec<u8>>) -> Result<Self, ArrayObjectError> {
+        let val = val
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| String::from_utf8(x).map_err(|_| ArrayObjectError::InvalidString(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(val.into())
+    }
+}
+
+impl TryFrom<OsString> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(val: OsString) -> Result<Self, Self::Error> {
+        let val = val
+            .into_string()
+            .map_err(|_| ArrayObjectError::InvalidString(0))?;
+        Ok(val.into())
+    }
+}
+
+impl TryFrom<Vec<OsString>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(val: Vec<OsString>) -> Result<Self, Self::Error> {
+        let val = val
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                x.into_string()
+                    .map_err(|_| ArrayObjectError::InvalidString(i))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(val.into())
+    }
+}
+
+impl TryFrom<VecShape<OsString>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(VecShape(val, shape): VecShape<OsString>) -> Result<Self, Self::Error> {
+        let mut temp: ArrayObject = val.try_into()?;
+        if temp.len() != shape.product() as usize {
+            return Err(ArrayObjectError::NumberOfElementsMismatch(
+                temp.len(),
+                shape.product() as usize,
+            ));
+        }
+        temp.shape = shape;
+        Ok(temp)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+
+macro_rules! restore {
+    ($obj:expr, $shape:expr, $variant:ident) => {{
+        let VecShape(data, _) = $obj.try_into()?;
+        AnyArray::$variant(VecShape(data, $shape))
+    }};
+}
+
+impl ArrayObject {
+    /// Inflates the data into the narrowest native type that holds all the elements.
+    /// Decimal numbers are restored as real numbers, while the time units are dropped, leaving the counts in the unit.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// use array_object::adaptor::*;
+    /// let obj: ArrayObject = vec![1u64, 2, 300].into();
+    /// let any = obj.into_any().unwrap();
+    /// assert_eq!(any, AnyArray::U16(VecShape(vec![1, 2, 300], vec![3])));
+    /// ```
+    pub fn into_any(self) -> Result<AnyArray, ArrayObjectError> {
+        let mut obj = self.descale(true, false)?;
+        obj.unit = None;
+        let shape = std::mem::take(&mut obj.shape);
+        obj.shape = vec![shape.product()];
+        let any = match obj.datatype {
+            DataType::UnsignedInteger => match integer_size(&obj) {
+                1 => restore!(obj, shape, U8),
+                2 => restore!(obj, shape, U16),
+                4 => restore!(obj, shape, U32),
+                8 => restore!(obj, shape, U64),
+                _ => restore!(obj, shape, U128),
+            },
+            DataType::SignedInteger => match integer_size(&obj) {
+                1 => restore!(obj, shape, I8),
+                2 => restore!(obj, shape, I16),
+                4 => restore!(obj, shape, I32),
+                8 => restore!(obj, shape, I64),
+                _ => restore!(obj, shape, I128),
+            },
+            DataType::Real => {
+                if narrow_float(&mut obj) {
+                    restore!(obj, shape, F32)
+                } else {
+                    restore!(obj, shape, F64)
+                }
+            }
+            DataType::Complex => {
+                if narrow_float(&mut obj) {
+                    restore!(obj, shape, C32)
+                } else {
+                    restore!(obj, shape, C64)
+                }
+            }
+            DataType::String => restore!(obj, shape, Str),
+            DataType::Bytes => restore!(obj, shape, Bytes),
+        };
+        Ok(any)
+    }
+}
+
+/// Returns the smallest size of the integer type, in bytes, that holds all the elements.
+fn integer_size(obj: &ArrayObject) -> usize {
+    let len = obj.len();
+    if len == 0 {
+        return 1;
+    }
+    let size_orig = obj.data.len() / len;
+    let min_size = obj
+        .data
+        .chunks(size_orig)
+        .map(|x| size_orig - x.iter().rev().position(|i| *i > 0).unwrap_or(size_orig - 1))
+        .max()
+        .unwrap_or(1);
+    match min_size {
+        1 => 1,
+        2 => 2,
+        3..=4 => 4,
+        5..=8 => 8,
+        _ => 16,
+    }
+}
+
+/// Converts the data into 32bit floats if it is lossless. Returns true if the data is stored in 32bit.
+fn narrow_float(obj: &mut ArrayObject) -> bool {
+    if obj.len() == 0 || obj.bits() == Some(32) {
+        return true;
+    }
+    let lossless = obj.data.chunks(8).all(|x| {
+        let x = f64::from_le_bytes(x.try_into().unwrap());
+        ((x as f32) as f64).to_bits() == x.to_bits()
+    });
+    if lossless {
+        obj.data = obj
+            .data
+            .chunks(8)
+            .flat_map(|x| (f64::from_le_bytes(x.try_into().unwrap()) as f32).to_le_bytes())
+            .collect();
+    }
+    lossless
+}
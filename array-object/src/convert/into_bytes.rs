@@ -0,0 +1,66 @@
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::pack::varint::varint_decode;
+use crate::storage::*;
+
+/// Splits the length-prefixed byte strings. Returns None if the data does not hold exactly `len` elements.
+pub(crate) fn split_bytes(data: &[u8], len: usize) -> Option<Vec<&[u8]>> {
+    let mut res = Vec::with_capacity(len);
+    let mut pos = 0;
+    while pos < data.len() {
+        let (n, k) = varint_decode(data[pos..].iter(), 1);
+        let start = pos + k;
+        let end = start.checked_add(*n.first()? as usize)?;
+        if end > data.len() {
+            return None;
+        }
+        res.push(&data[start..end]);
+        pos = end;
+    }
+    if res.len() == len { Some(res) } else { None }
+}
+
+impl ArrayObject {
+    /// Returns the raw bytes of each element of string or bytes data.
+    pub(crate) fn elements(&self) -> Option<Vec<&[u8]>> {
+        match self.datatype {
+            DataType::String if self.len() == 0 => Some(vec![]),
+            DataType::String => Some(self.data.split(|&x| x == 255).collect()),
+            DataType::Bytes => split_bytes(&self.data, self.len()),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<ArrayObject> for VecShape<Vec<u8>> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let val = val.into_valid()?;
+        if val.shape.is_empty() || val.datatype != DataType::Bytes {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+        let data = split_bytes(&val.data, val.len())
+            .ok_or(ArrayObjectError::UnableToDecode)?
+            .into_iter()
+            .map(|x| x.to_vec())
+            .collect();
+        Ok(VecShape(data, val.shape))
+    }
+}
+
+impl TryFrom<ArrayObject> for Vec<Vec<u8>> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        if val.shape.len() != 1 {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+        let VecShape::<Vec<u8>>(data, _) = val.try_into()?;
+        Ok(data)
+    }
+}
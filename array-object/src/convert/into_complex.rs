@@ -9,6 +9,7 @@ macro_rules! into_complex {
             impl TryFrom<ArrayObject> for Pair<$ty> {
                 type Error = ArrayObjectError;
                 fn try_from(mut val: ArrayObject) -> Result<Self, Self::Error> {
+                    val = val.into_valid()?.promote(DataType::Complex)?;
                     if !val.shape.is_empty() || val.datatype != DataType::Complex {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -43,6 +44,7 @@ macro_rules! into_complex {
             impl TryFrom<ArrayObject> for VecShape<Pair<$ty>> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = val.into_valid()?.promote(DataType::Complex)?;
                     if val.shape.is_empty() || val.datatype != DataType::Complex {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -80,6 +82,7 @@ macro_rules! into_complex {
             impl TryFrom<ArrayObject> for VecShape<Complex<$ty>> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = val.into_valid()?.promote(DataType::Complex)?;
                     if val.shape.is_empty() || val.datatype != DataType::Complex {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -117,6 +120,7 @@ macro_rules! into_complex {
             impl TryFrom<ArrayObject> for VecVecShape<$ty> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = val.into_valid()?.promote(DataType::Complex)?;
                     if val.shape.is_empty() || val.datatype != DataType::Complex {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -1,4 +1,5 @@
 use crate::adaptor::*;
+use crate::convert::cast::narrow_integer;
 use crate::convert::zigzag::Zigzag;
 use crate::error::ArrayObjectError;
 use crate::storage::*;
@@ -9,6 +10,7 @@ macro_rules! into_integer {
             impl TryFrom<ArrayObject> for $ty {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = narrow_integer(val.into_valid()?.descale(false, false)?)?;
                     if !val.shape.is_empty() {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -78,6 +80,7 @@ macro_rules! into_integer {
             impl TryFrom<ArrayObject> for VecShape<$ty> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = narrow_integer(val.into_valid()?.descale(false, false)?)?;
                     if val.shape.is_empty() {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -8,6 +8,7 @@ macro_rules! into_float {
             impl TryFrom<ArrayObject> for $ty {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = val.into_valid()?.promote(DataType::Real)?;
                     if !val.shape.is_empty() || val.datatype != DataType::Real {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -31,6 +32,7 @@ macro_rules! into_float {
             impl TryFrom<ArrayObject> for VecShape<$ty> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let val = val.into_valid()?.promote(DataType::Real)?;
                     if val.shape.is_empty() || val.datatype != DataType::Real {
                         return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
                     }
@@ -1,23 +1,26 @@
 use crate::adaptor::*;
 use crate::error::ArrayObjectError;
 use crate::storage::*;
+use std::ffi::OsString;
 
 impl TryFrom<ArrayObject> for String {
     type Error = ArrayObjectError;
     fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let val = val.into_valid()?;
         if !val.shape.is_empty() || val.datatype != DataType::String {
             return Err(ArrayObjectError::WrongDataType(
                 val.datatype,
                 val.shape.len(),
             ));
         }
-        Ok(String::from_utf8(val.data).unwrap())
+        String::from_utf8(val.data).map_err(|_| ArrayObjectError::UnableToDecode)
     }
 }
 
 impl TryFrom<ArrayObject> for VecShape<String> {
     type Error = ArrayObjectError;
     fn try_from(mut val: ArrayObject) -> Result<Self, Self::Error> {
+        val = val.into_valid()?;
         if val.shape.is_empty() || val.datatype != DataType::String {
             return Err(ArrayObjectError::WrongDataType(
                 val.datatype,
@@ -27,14 +30,15 @@ impl TryFrom<ArrayObject> for VecShape<String> {
         if val.len() == 0 {
             return Ok(VecShape(vec![], val.shape));
         }
-        let mut data = vec![];
-        while let Some(p) = val.data.iter().position(|&x| x == 255) {
-            let mut s = val.data.split_off(p + 1);
-            std::mem::swap(&mut s, &mut val.data);
-            s.pop();
-            data.push(String::from_utf8(s).unwrap());
+        let data = val
+            .data
+            .split(|&x| x == 255)
+            .map(|x| String::from_utf8(x.to_vec()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ArrayObjectError::UnableToDecode)?;
+        if data.len() != val.len() {
+            return Err(ArrayObjectError::UnableToDecode);
         }
-        data.push(String::from_utf8(val.data).unwrap());
         Ok(VecShape(data, val.shape))
     }
 }
@@ -66,3 +70,18 @@ impl<const N: usize> TryFrom<ArrayObject> for [String; N] {
         Ok(data.try_into().unwrap())
     }
 }
+
+impl TryFrom<ArrayObject> for OsString {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        Ok(String::try_from(val)?.into())
+    }
+}
+
+impl TryFrom<ArrayObject> for Vec<OsString> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let data: Vec<String> = val.try_into()?;
+        Ok(data.into_iter().map(OsString::from).collect())
+    }
+}
@@ -1,9 +1,17 @@
+pub(crate) mod cast;
+mod from_any;
+pub(crate) mod from_bytes;
+mod from_duration;
 mod from_complex;
 mod from_integer;
 mod from_real;
 mod from_string;
+mod into_any;
+pub(crate) mod into_bytes;
 mod into_complex;
 mod into_integer;
 mod into_real;
 mod into_string;
-mod zigzag;
\ No newline at end of file
+pub(crate) mod zigzag;
+
+pub use cast::CastOption;
\ No newline at end of file
@@ -0,0 +1,301 @@
+use crate::convert::zigzag::Zigzag;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::Unit;
+use std::fmt::{Display, Formatter, Result};
+
+/// A formatter of ArrayObject with options. See `ArrayObject::display()`.
+pub struct ArrayDisplay<'a> {
+    obj: &'a ArrayObject,
+    edge_items: usize,
+    threshold: usize,
+}
+
+impl ArrayObject {
+    /// Returns a formatter of the array. Arrays with more elements than the threshold (1000 by default) are summarized, showing the edge items (3 by default) of each axis.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = (0..100u32).collect::<Vec<_>>().into();
+    /// let text = format!("{}", obj.display().edge_items(2).threshold(10));
+    /// assert_eq!(text, "[ 0,  1, ..., 98, 99]");
+    /// ```
+    pub fn display(&self) -> ArrayDisplay<'_> {
+        ArrayDisplay {
+            obj: self,
+            edge_items: 3,
+            threshold: 1000,
+        }
+    }
+}
+
+impl ArrayDisplay<'_> {
+    /// Sets the number of items shown at the beginning and the end of each axis when summarized.
+    pub fn edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        self
+    }
+    /// Sets the total number of elements above which the array is summarized.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+    /// Collects the indices of the elements to be shown, in the order of appearance.
+    fn visible(&self, shape: &[u64], offset: usize, summarize: bool, res: &mut Vec<usize>) {
+        if shape.is_empty() {
+            res.push(offset);
+            return;
+        }
+        let stride = shape[1..].iter().product::<u64>() as usize;
+        for i in self
+            .indices(shape[0] as usize, summarize)
+            .into_iter()
+            .flatten()
+        {
+            self.visible(&shape[1..], offset + i * stride, summarize, res);
+        }
+    }
+    fn indices(&self, n: usize, summarize: bool) -> Vec<Option<usize>> {
+        if summarize && n > 2 * self.edge_items {
+            (0..self.edge_items)
+                .map(Some)
+                .chain([None])
+                .chain((n - self.edge_items..n).map(Some))
+                .collect()
+        } else {
+            (0..n).map(Some).collect()
+        }
+    }
+    fn write_axis(
+        &self,
+        f: &mut Formatter<'_>,
+        shape: &[u64],
+        depth: usize,
+        summarize: bool,
+        elements: &mut impl Iterator<Item = String>,
+        width: usize,
+    ) -> Result {
+        if shape.is_empty() {
+            return write!(f, "{:>width$}", elements.next().unwrap_or_default());
+        }
+        write!(f, "[")?;
+        for (k, i) in self
+            .indices(shape[0] as usize, summarize)
+            .into_iter()
+            .enumerate()
+        {
+            if k > 0 {
+                if shape.len() == 1 {
+                    write!(f, ", ")?;
+                } else {
+                    write!(f, ",")?;
+                    for _ in 1..shape.len() {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", " ".repeat(depth + 1))?;
+                }
+            }
+            match i {
+                Some(_) => {
+                    self.write_axis(f, &shape[1..], depth + 1, summarize, elements, width)?
+                }
+                None => write!(f, "...")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+impl Display for ArrayDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let obj = self.obj;
+        let summarize = obj.shape.product() as usize > self.threshold;
+        let mut indices = vec![];
+        self.visible(&obj.shape, 0, summarize, &mut indices);
+        let elements: Vec<String> = format_elements(obj, &indices, f.precision())
+            .into_iter()
+            .zip(&indices)
+            .map(|(x, &i)| if obj.is_valid(i) { x } else { "None".to_string() })
+            .collect();
+        let width = elements
+            .iter()
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+        self.write_axis(
+            f,
+            &obj.shape,
+            0,
+            summarize,
+            &mut elements.into_iter(),
+            width,
+        )
+    }
+}
+
+impl Display for ArrayObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.display().fmt(f)
+    }
+}
+
+fn format_float(x: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => format!("{x:.p$}"),
+        None => format!("{x:?}"),
+    }
+}
+
+fn format_complex(re: String, im: String) -> String {
+    match im.strip_prefix('-') {
+        Some(im) => format!("{re}-{im}i"),
+        None => format!("{re}+{im}i"),
+    }
+}
+
+/// Formats an integer longer than 128 bits in decimal.
+fn format_wide(bytes: &[u8], signed: bool) -> String {
+    let mut x = bytes.to_vec();
+    let negative = signed && x[0] & 1 == 1;
+    if signed {
+        // Zigzag decoding: the absolute value is (x + 1) / 2 for negative numbers and x / 2 otherwise.
+        let mut carry = negative as u8;
+        for b in x.iter_mut() {
+            let (v, c) = b.overflowing_add(carry);
+            *b = v;
+            carry = c as u8;
+        }
+        for b in x.iter_mut().rev() {
+            let low = *b & 1;
+            *b = (*b >> 1) | (carry << 7);
+            carry = low;
+        }
+    }
+    let mut digits = vec![];
+    while x.iter().any(|&b| b > 0) {
+        let mut rem = 0u32;
+        for b in x.iter_mut().rev() {
+            let cur = rem * 256 + *b as u32;
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(char::from(b'0' + rem as u8));
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+    if negative {
+        digits.push('-');
+    }
+    digits.into_iter().rev().collect()
+}
+
+/// Places the decimal point in the mantissa, falling back to the exponent notation for large exponents.
+fn format_decimal(mantissa: String, exponent: i32) -> String {
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", mantissa.as_str()),
+    };
+    match exponent {
+        0 => mantissa,
+        1..=40 if digits == "0" => mantissa,
+        1..=40 => format!("{mantissa}{}", "0".repeat(exponent as usize)),
+        -40..0 => {
+            let scale = exponent.unsigned_abs() as usize;
+            let digits = format!("{digits:0>width$}", width = scale + 1);
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            format!("{sign}{int}.{frac}")
+        }
+        _ => format!("{mantissa}e{exponent}"),
+    }
+}
+
+/// Decodes and formats the elements at the indices.
+fn format_elements(obj: &ArrayObject, indices: &[usize], precision: Option<usize>) -> Vec<String> {
+    match obj.unit {
+        Some(Unit::Decimal(exponent)) => format_values(obj, indices, precision)
+            .into_iter()
+            .map(|x| format_decimal(x, exponent))
+            .collect(),
+        _ => format_values(obj, indices, precision),
+    }
+}
+
+fn format_values(obj: &ArrayObject, indices: &[usize], precision: Option<usize>) -> Vec<String> {
+    let len = obj.len();
+    if len == 0 {
+        return vec![];
+    }
+    let size = obj.data.len() / len;
+    let bytes = |i: usize| &obj.data[i * size..(i + 1) * size];
+    match obj.datatype {
+        DataType::UnsignedInteger | DataType::SignedInteger if size > 16 => indices
+            .iter()
+            .map(|&i| format_wide(bytes(i), obj.datatype == DataType::SignedInteger))
+            .collect(),
+        DataType::UnsignedInteger => indices
+            .iter()
+            .map(|&i| {
+                let mut x = [0u8; 16];
+                x[..size].copy_from_slice(bytes(i));
+                u128::from_le_bytes(x).to_string()
+            })
+            .collect(),
+        DataType::SignedInteger => indices
+            .iter()
+            .map(|&i| {
+                let mut x = [0u8; 16];
+                x[..size].copy_from_slice(bytes(i));
+                i128::from_le_bytes(x).straight().to_string()
+            })
+            .collect(),
+        DataType::Real => indices
+            .iter()
+            .map(|&i| match size {
+                4 => match precision {
+                    Some(p) => format!("{:.p$}", f32::from_le_bytes(bytes(i).try_into().unwrap())),
+                    None => format!("{:?}", f32::from_le_bytes(bytes(i).try_into().unwrap())),
+                },
+                _ => format_float(f64::from_le_bytes(bytes(i).try_into().unwrap()), precision),
+            })
+            .collect(),
+        DataType::Complex => indices
+            .iter()
+            .map(|&i| {
+                let (re, im) = bytes(i).split_at(size / 2);
+                match size {
+                    8 => {
+                        let re = f32::from_le_bytes(re.try_into().unwrap());
+                        let im = f32::from_le_bytes(im.try_into().unwrap());
+                        match precision {
+                            Some(p) => format_complex(format!("{re:.p$}"), format!("{im:.p$}")),
+                            None => format_complex(format!("{re:?}"), format!("{im:?}")),
+                        }
+                    }
+                    _ => {
+                        let re = f64::from_le_bytes(re.try_into().unwrap());
+                        let im = f64::from_le_bytes(im.try_into().unwrap());
+                        format_complex(format_float(re, precision), format_float(im, precision))
+                    }
+                }
+            })
+            .collect(),
+        DataType::String => {
+            let strings: Vec<_> = obj.data.split(|&x| x == 255).collect();
+            indices
+                .iter()
+                .map(|&i| format!("{:?}", String::from_utf8_lossy(strings[i])))
+                .collect()
+        }
+        DataType::Bytes => {
+            let blobs = obj.elements().unwrap_or_default();
+            indices
+                .iter()
+                .map(|&i| match blobs.get(i) {
+                    Some(x) => format!("b\"{}\"", x.escape_ascii()),
+                    None => "?".to_string(),
+                })
+                .collect()
+        }
+    }
+}
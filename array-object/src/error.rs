@@ -2,16 +2,36 @@ use std::error::Error;
 use std::fmt::{Debug, Display};
 
 use crate::storage::DataType;
+use crate::unit::Unit;
 
 pub enum ArrayObjectError {
     VectorLengthMismatch(usize, usize),
     NumberOfElementsMismatch(usize, usize),
+    #[deprecated(note = "Arrays of more than 15 dimensions are stored with the dimension extension, so this is never returned.")]
     TooLargeDimension(usize),
     WrongDataType(DataType, usize),
     LossyConversion,
     ConcatShapeMismatch,
+    EmptyConcat,
+    AxisOutOfRange(usize, usize),
+    IndivisibleSplit(usize, usize),
     UnableToDecode,
     IncompatibleConversion(bool, usize),
+    UnsupportedCast(DataType, DataType),
+    UndefinedOperation(&'static str, DataType),
+    ShapeMismatch(Vec<usize>, Vec<usize>),
+    EmptyArray,
+    Overflow,
+    NullElement,
+    InvalidString(usize),
+    WrongUnit(Option<Unit>, Unit),
+    ChecksumMismatch(u32, u32),
+    UnsupportedExtension(u8),
+    UnsupportedVersion(u8),
+    Io(std::io::Error),
+    VerificationFailed,
+    NameNotFound(String),
+    DuplicateName(String),
     External(&'static str),
 }
 
@@ -27,6 +47,7 @@ impl Display for ArrayObjectError {
             ArrayObjectError::NumberOfElementsMismatch(vec_len, total_len) => {
                 write!(f, "The length of the vector should agree with the number of elements in the array: expected {total_len}, found {vec_len}.")
             }
+            #[allow(deprecated)]
             ArrayObjectError::TooLargeDimension(dim) => {
                 write!(
                     f,
@@ -38,10 +59,25 @@ impl Display for ArrayObjectError {
                 _ => write!(f, "The wrong data type: expected [{dim}D Array of {ty:?}]."),
             },
             ArrayObjectError::LossyConversion => {
-                write!(f, "The lossy conversion of float numbers is disabled by default. See [features] allow_float_down_convert.")
+                write!(f, "The data cannot be converted without loss of precision. A lossy conversion needs to be requested explicitly, e.g. with CastOption::Lossy, or with [features] allow_float_down_convert for float numbers.")
             }
             ArrayObjectError::ConcatShapeMismatch => {
-                write!(f, "The concatenation of the ArrayObject is only allowed for the ones having the same size, shape and type.")
+                write!(f, "The concatenation of the ArrayObject is only allowed for the ones having the same type and the same shape except for the concatenation axis.")
+            }
+            ArrayObjectError::EmptyConcat => {
+                write!(
+                    f,
+                    "At least one ArrayObject is required for the concatenation."
+                )
+            }
+            ArrayObjectError::AxisOutOfRange(axis, dim) => {
+                write!(f, "The axis is out of range: axis {axis} for {dim}D array.")
+            }
+            ArrayObjectError::IndivisibleSplit(len, sections) => {
+                write!(
+                    f,
+                    "The axis of length {len} cannot be split into {sections} equal sections."
+                )
             }
             ArrayObjectError::UnableToDecode => {
                 write!(f, "The data is either broken or wrong.")
@@ -59,6 +95,73 @@ impl Display for ArrayObjectError {
                     )
                 }
             }
+            ArrayObjectError::UnsupportedCast(from, to) => {
+                write!(f, "The cast from {from:?} into {to:?} is not supported.")
+            }
+            ArrayObjectError::UndefinedOperation(op, ty) => {
+                write!(f, "The operation `{op}` is not defined for {ty:?}.")
+            }
+            ArrayObjectError::ShapeMismatch(lhs, rhs) => {
+                write!(f, "The shapes of the arrays do not agree: {lhs:?} != {rhs:?}.")
+            }
+            ArrayObjectError::EmptyArray => {
+                write!(f, "The operation is not defined for an empty array.")
+            }
+            ArrayObjectError::Overflow => {
+                write!(f, "The result of the integer operation overflows.")
+            }
+            ArrayObjectError::NullElement => {
+                write!(
+                    f,
+                    "The array contains null elements. Restore it into `Option<T>` instead."
+                )
+            }
+            ArrayObjectError::InvalidString(index) => {
+                write!(
+                    f,
+                    "The element {index} is not a valid UTF-8 string. Store it as bytes instead."
+                )
+            }
+            ArrayObjectError::WrongUnit(found, expected) => match found {
+                Some(found) => write!(
+                    f,
+                    "The wrong unit: expected {expected:?}, found {found:?}."
+                ),
+                None => write!(f, "The wrong unit: expected {expected:?}, found none."),
+            },
+            ArrayObjectError::ChecksumMismatch(expected, found) => {
+                write!(
+                    f,
+                    "The checksum does not match: expected {expected:#010x}, found {found:#010x}. The data is corrupted."
+                )
+            }
+            ArrayObjectError::UnsupportedExtension(code) => {
+                write!(
+                    f,
+                    "Unsupported extension {code}. The data may be written by a newer version."
+                )
+            }
+            ArrayObjectError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "Unsupported version {version} of the extension footer. The data may be written by a newer version."
+                )
+            }
+            ArrayObjectError::Io(err) => {
+                write!(f, "I/O error: {err}")
+            }
+            ArrayObjectError::VerificationFailed => {
+                write!(
+                    f,
+                    "The written file does not restore the original object. The target is left unchanged."
+                )
+            }
+            ArrayObjectError::NameNotFound(name) => {
+                write!(f, "The container has no object named `{name}`.")
+            }
+            ArrayObjectError::DuplicateName(name) => {
+                write!(f, "The container already has an object named `{name}`.")
+            }
             ArrayObjectError::External(err) => {
                 write!(f, "{err}")
             }
@@ -72,4 +175,23 @@ impl Debug for ArrayObjectError {
     }
 }
 
-impl Error for ArrayObjectError {}
+impl Error for ArrayObjectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrayObjectError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::convert::Infallible> for ArrayObjectError {
+    fn from(err: std::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<std::io::Error> for ArrayObjectError {
+    fn from(err: std::io::Error) -> Self {
+        ArrayObjectError::Io(err)
+    }
+}
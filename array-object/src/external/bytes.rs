@@ -0,0 +1,71 @@
+use bytes::Bytes;
+
+use crate::adaptor::*;
+use crate::convert::from_bytes::join_bytes;
+use crate::convert::into_bytes::split_bytes;
+use crate::error::ArrayObjectError;
+use crate::storage::*;
+
+impl From<Bytes> for ArrayObject {
+    fn from(val: Bytes) -> Self {
+        Self {
+            data: join_bytes([val]),
+            shape: vec![],
+            datatype: DataType::Bytes,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+impl From<Vec<Bytes>> for ArrayObject {
+    fn from(val: Vec<Bytes>) -> Self {
+        let shape = vec![val.len() as u64];
+        Self {
+            data: join_bytes(val),
+            shape,
+            datatype: DataType::Bytes,
+            validity: None,
+            unit: None,
+        }
+    }
+}
+
+impl TryFrom<VecShape<Bytes>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(VecShape(val, shape): VecShape<Bytes>) -> Result<Self, Self::Error> {
+        let val = val.into_iter().map(|x| x.to_vec()).collect();
+        VecShape::<Vec<u8>>(val, shape).try_into()
+    }
+}
+
+impl TryFrom<ArrayObject> for Bytes {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let val = val.into_valid()?;
+        if !val.shape.is_empty() || val.datatype != DataType::Bytes {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+        let data = split_bytes(&val.data, 1).ok_or(ArrayObjectError::UnableToDecode)?;
+        Ok(Bytes::copy_from_slice(data[0]))
+    }
+}
+
+impl TryFrom<ArrayObject> for VecShape<Bytes> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let VecShape::<Vec<u8>>(data, shape) = val.try_into()?;
+        Ok(VecShape(data.into_iter().map(Bytes::from).collect(), shape))
+    }
+}
+
+impl TryFrom<ArrayObject> for Vec<Bytes> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        let data: Vec<Vec<u8>> = val.try_into()?;
+        Ok(data.into_iter().map(Bytes::from).collect())
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::*;
+
+fn nanos(secs: i64, subsec_nanos: i64) -> i128 {
+    secs as i128 * 1_000_000_000 + subsec_nanos as i128
+}
+
+fn split_nanos(x: i128) -> Option<(i64, u32)> {
+    let secs = i64::try_from(x.div_euclid(1_000_000_000)).ok()?;
+    Some((secs, x.rem_euclid(1_000_000_000) as u32))
+}
+
+time_impl!(
+    DateTime<Utc>,
+    Unit::Timestamp(TimeUnit::Nanosecond),
+    |x: &DateTime<Utc>| nanos(x.timestamp(), x.timestamp_subsec_nanos() as i64),
+    |x: i128| {
+        let (secs, nsecs) = split_nanos(x)?;
+        DateTime::from_timestamp(secs, nsecs)
+    }
+);
+
+time_impl!(
+    NaiveDate,
+    Unit::Date,
+    |x: &NaiveDate| (*x - DateTime::UNIX_EPOCH.date_naive()).num_days() as i128,
+    |x: i128| DateTime::UNIX_EPOCH
+        .date_naive()
+        .checked_add_signed(TimeDelta::try_days(i64::try_from(x).ok()?)?)
+);
+
+time_impl!(
+    TimeDelta,
+    Unit::Duration(TimeUnit::Nanosecond),
+    |x: &TimeDelta| nanos(x.num_seconds(), x.subsec_nanos() as i64),
+    |x: i128| {
+        let (secs, nsecs) = split_nanos(x)?;
+        TimeDelta::new(secs, nsecs)
+    }
+);
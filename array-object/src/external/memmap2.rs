@@ -0,0 +1,136 @@
+use std::fs::File;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use memmap2::Mmap;
+
+use crate::bitfield::*;
+use crate::error::ArrayObjectError;
+use crate::external::native::NativeElement;
+use crate::misc::Product;
+use crate::pack::Unpack;
+use crate::pack::varint::varint_decode;
+use crate::storage::*;
+
+/// An object in a memory-mapped file, created by [`ArrayObject::open_mmap`].
+///
+/// Arrays of unsigned integers, real numbers and complex numbers stored with the fixed length are accessed without copying if the width of the elements is the native one, e.g. written by `pack_as_it_is()`. The other formats are decoded on the first access.
+pub struct MmapObject {
+    mmap: Mmap,
+    native: Option<(DataType, usize, Vec<u64>)>,
+    object: OnceLock<ArrayObject>,
+}
+
+impl ArrayObject {
+    /// Maps the file into memory and reads the footer from the end of the mapping.
+    ///
+    /// The file must not be modified while it is mapped.
+    pub fn open_mmap(path: impl AsRef<Path>) -> Result<MmapObject, ArrayObjectError> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read only, and the caller keeps the file unchanged while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+        if mmap.is_empty() {
+            return Err(ArrayObjectError::UnableToDecode);
+        }
+        let native = read_native_footer(&mmap);
+        Ok(MmapObject {
+            mmap,
+            native,
+            object: OnceLock::new(),
+        })
+    }
+}
+
+/// Reads the footer of a fixed length array of numbers and returns the data type, the length of the payload and the shape.
+fn read_native_footer(binary: &[u8]) -> Option<(DataType, usize, Vec<u64>)> {
+    let last = *binary.last()?;
+    let datatype = match last & TYPE_MASK {
+        UNSIGNED_INTEGER => DataType::UnsignedInteger,
+        REAL => DataType::Real,
+        COMPLEX => DataType::Complex,
+        _ => return None,
+    };
+    if last & FORMAT_MASK != FIXED_LENGTH {
+        return None;
+    }
+    let dim = (last & DIMENSION_MASK) as usize;
+    let (shape, len) = varint_decode(binary[..binary.len() - 1].iter().rev(), dim);
+    if shape.len() != dim {
+        return None;
+    }
+    Some((datatype, binary.len() - 1 - len, shape))
+}
+
+impl MmapObject {
+    /// Returns the decoded object. The data is copied and decoded only on the first call.
+    pub fn object(&self) -> Result<&ArrayObject, ArrayObjectError> {
+        if let Some(obj) = self.object.get() {
+            return Ok(obj);
+        }
+        let obj = ArrayObject::unpack(self.mmap.to_vec())?;
+        Ok(self.object.get_or_init(|| obj))
+    }
+    /// Returns the shape of the array.
+    pub fn shape(&self) -> Result<Vec<usize>, ArrayObjectError> {
+        match &self.native {
+            Some((_, _, shape)) => Ok(shape.iter().map(|&x| x as usize).collect()),
+            None => Ok(self.object()?.shape()),
+        }
+    }
+    /// Returns the data type.
+    pub fn datatype(&self) -> Result<DataType, ArrayObjectError> {
+        match &self.native {
+            Some((datatype, _, _)) => Ok(datatype.clone()),
+            None => Ok(self.object()?.datatype()),
+        }
+    }
+    /// Returns the size of the mapped file.
+    pub fn filesize(&self) -> usize {
+        self.mmap.len()
+    }
+    /// Returns the elements as a slice of the mapping without copying.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1.5f64, 2.5, 3.5].into();
+    /// let path = std::env::temp_dir().join("mmap.bin");
+    /// std::fs::write(&path, obj.pack_as_it_is()).unwrap();
+    /// let mapped = ArrayObject::open_mmap(&path).unwrap();
+    /// assert_eq!(mapped.as_slice::<f64>().unwrap(), &[1.5, 2.5, 3.5]);
+    /// ```
+    pub fn as_slice<T: NativeElement>(&self) -> Result<&[T], ArrayObjectError> {
+        if cfg!(target_endian = "big") {
+            return Err(ArrayObjectError::External(
+                "Zero-copy views are only available on little-endian targets.",
+            ));
+        }
+        let Some((datatype, len, shape)) = &self.native else {
+            return Err(ArrayObjectError::External(
+                "The data is not stored with the fixed length. Use object() instead.",
+            ));
+        };
+        if *datatype != T::DATATYPE {
+            return Err(ArrayObjectError::WrongDataType(
+                datatype.clone(),
+                shape.len(),
+            ));
+        }
+        if *len != shape.product() as usize * size_of::<T>() {
+            return Err(ArrayObjectError::External(
+                "The element width does not match the requested type.",
+            ));
+        }
+        if *len == 0 {
+            return Ok(&[]);
+        }
+        if self.mmap.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(ArrayObjectError::External(
+                "The buffer is not aligned for the requested type.",
+            ));
+        }
+        // SAFETY: the length and the alignment are checked above, and `T` accepts any bit pattern.
+        Ok(unsafe {
+            std::slice::from_raw_parts(self.mmap.as_ptr() as *const T, len / size_of::<T>())
+        })
+    }
+}
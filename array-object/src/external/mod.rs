@@ -1,4 +1,40 @@
-#[cfg(any(feature = "ndarray_15", feature = "ndarray_16"))]
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(any(feature = "ndarray_15", feature = "ndarray_16", feature = "ndarray_17"))]
 mod ndarray;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "memmap2")]
+mod memmap2;
+#[cfg(feature = "memmap2")]
+pub use memmap2::MmapObject;
 #[cfg(feature = "nalgebra")]
-mod nalgebra;
\ No newline at end of file
+mod nalgebra;
+#[cfg(feature = "nalgebra_sparse")]
+mod nalgebra_sparse;
+#[cfg(any(
+    feature = "ndarray_15",
+    feature = "ndarray_16",
+    feature = "ndarray_17",
+    feature = "memmap2"
+))]
+mod native;
+#[cfg(any(
+    feature = "ndarray_15",
+    feature = "ndarray_16",
+    feature = "ndarray_17",
+    feature = "memmap2"
+))]
+pub use native::NativeElement;
+#[cfg(feature = "num_bigint")]
+mod num_bigint;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(any(feature = "nalgebra_sparse", feature = "sprs"))]
+mod sparse;
+#[cfg(feature = "sprs")]
+mod sprs;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "tokio")]
+mod tokio;
@@ -2,13 +2,38 @@ use nalgebra::base::allocator::Allocator;
 use nalgebra::base::default_allocator::DefaultAllocator;
 use nalgebra::base::dimension::Dim;
 use nalgebra::base::storage::RawStorage;
-use nalgebra::base::{DMatrix, DVector, Matrix};
+use nalgebra::base::{Matrix, OMatrix};
 use nalgebra::Complex;
 
 use crate::adaptor::*;
 use crate::error::ArrayObjectError;
 use crate::ArrayObject;
 
+fn accepts<D: Dim>(n: usize) -> bool {
+    D::try_to_usize().is_none_or(|d| d == n)
+}
+
+/// Determines the (rows, columns) of the target matrix. A vector becomes a column if the target allows it, otherwise a row.
+fn matrix_shape<R: Dim, C: Dim>(shape: &[u64]) -> Result<(usize, usize), ArrayObjectError> {
+    let (nrows, ncols) = match shape.len() {
+        1 => {
+            let n = shape[0] as usize;
+            if accepts::<R>(n) && accepts::<C>(1) {
+                (n, 1)
+            } else {
+                (1, n)
+            }
+        }
+        2 => (shape[0] as usize, shape[1] as usize),
+        _ => return Err(ArrayObjectError::External("The data is not a matrix or a vector")),
+    };
+    if accepts::<R>(nrows) && accepts::<C>(ncols) {
+        Ok((nrows, ncols))
+    } else {
+        Err(ArrayObjectError::External("The shape does not match the dimensions of the matrix"))
+    }
+}
+
 macro_rules! nalgebra_impl {
     ($($ty:ty),*) => {
         $(
@@ -28,32 +53,12 @@ macro_rules! nalgebra_impl {
                     VecShape(v, shape).try_into()
                 }
             }
-            impl TryFrom<ArrayObject> for DMatrix<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    match shape.len() {
-                        1 => {
-                            let temp = DMatrix::from_vec(1, shape[0] as usize, data);
-                            Ok(temp.transpose())
-                        },
-                        2 => {
-                            let temp = DMatrix::from_vec(shape[1] as usize, shape[0] as usize, data);
-                            Ok(temp.transpose())
-                        },
-                        _ => Err(ArrayObjectError::External("The data is not a matrix or a vector"))
-                    }
-                }
-            }
-            impl TryFrom<ArrayObject> for DVector<$ty> {
+            impl<R: Dim, C: Dim> TryFrom<ArrayObject> for OMatrix<$ty, R, C> where DefaultAllocator: Allocator<R, C> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
                     let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    if shape.len() == 1 {
-                        let temp = DVector::from_vec(data);
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("The data is not a vector"))}
+                    let (nrows, ncols) = matrix_shape::<R, C>(&shape)?;
+                    Ok(Matrix::from_row_slice_generic(R::from_usize(nrows), C::from_usize(ncols), &data))
                 }
             }
         )*
@@ -0,0 +1,87 @@
+use nalgebra_sparse::{CooMatrix, CsrMatrix};
+use num_complex::Complex;
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::ArrayObject;
+use crate::external::sparse::*;
+
+fn into_coo<T: nalgebra_sparse::na::Scalar + Default>(
+    val: ArrayObject,
+) -> Result<CooMatrix<T>, ArrayObjectError>
+where
+    VecShape<T>: TryFrom<ArrayObject, Error = ArrayObjectError>,
+{
+    let VecShape::<T>(data, shape) = val.try_into()?;
+    let (nrows, ncols) = matrix_shape(&shape)?;
+    let mut coo = CooMatrix::new(nrows, ncols);
+    for (i, j, v) in triplets(data, ncols) {
+        coo.push(i, j, v);
+    }
+    Ok(coo)
+}
+
+macro_rules! nalgebra_sparse_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<&CooMatrix<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: &CooMatrix<$ty>) -> Result<Self, Self::Error> {
+                    let triplets = val.triplet_iter().map(|(i, j, v)| (i, j, *v));
+                    from_triplets(val.nrows(), val.ncols(), triplets)
+                }
+            }
+            impl TryFrom<CooMatrix<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: CooMatrix<$ty>) -> Result<Self, Self::Error> {
+                    (&val).try_into()
+                }
+            }
+            impl TryFrom<&CsrMatrix<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: &CsrMatrix<$ty>) -> Result<Self, Self::Error> {
+                    let triplets = val.triplet_iter().map(|(i, j, v)| (i, j, *v));
+                    from_triplets(val.nrows(), val.ncols(), triplets)
+                }
+            }
+            impl TryFrom<CsrMatrix<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: CsrMatrix<$ty>) -> Result<Self, Self::Error> {
+                    (&val).try_into()
+                }
+            }
+            impl TryFrom<ArrayObject> for CooMatrix<$ty> {
+                type Error = ArrayObjectError;
+                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    into_coo(val)
+                }
+            }
+            impl TryFrom<ArrayObject> for CsrMatrix<$ty> {
+                type Error = ArrayObjectError;
+                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    let coo = into_coo::<$ty>(val)?;
+                    Ok(CsrMatrix::from(&coo))
+                }
+            }
+        )*
+    };
+}
+
+nalgebra_sparse_impl!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    Complex<f32>,
+    Complex<f64>
+);
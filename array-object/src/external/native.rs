@@ -0,0 +1,26 @@
+use crate::storage::DataType;
+use num_complex::Complex;
+
+/// Element types whose in-memory layout coincides with the unpacked buffer, so that it can be viewed without copying.
+///
+/// # Safety
+/// Implementors must be plain little-endian numbers without padding, for which every bit pattern is valid.
+pub unsafe trait NativeElement: Copy {
+    /// The data type of an object that stores this element type natively.
+    const DATATYPE: DataType;
+}
+
+macro_rules! native_impl {
+    ($datatype:ident, $($ty:ty),*) => {
+        $(
+            unsafe impl NativeElement for $ty {
+                const DATATYPE: DataType = DataType::$datatype;
+            }
+        )*
+    };
+}
+
+// Signed integers are zigzag-encoded in the buffer and therefore have no native view.
+native_impl!(UnsignedInteger, u8, u16, u32, u64, u128);
+native_impl!(Real, f32, f64);
+native_impl!(Complex, Complex<f32>, Complex<f64>);
@@ -2,139 +2,154 @@
 use ndarray_15 as ndarray;
 #[cfg(feature = "ndarray_16")]
 use ndarray_16 as ndarray;
+#[cfg(feature = "ndarray_17")]
+use ndarray_17 as ndarray;
 
 use crate::adaptor::*;
+use crate::external::native::NativeElement;
 use crate::error::ArrayObjectError;
 use crate::misc::TryConcat;
-use crate::ArrayObject;
-use ndarray::{Array, Array0, Array1, Array2, Array3, Array4, Array5, Array6, ArrayD, Dimension, ArrayView};
+use crate::storage::ArrayObject;
+use ndarray::{
+    ArcArray, Array, Array1, ArrayBase, ArrayViewD, ArrayViewMutD, CowArray, Data, Dimension, IxDyn,
+};
 use num_complex::Complex;
 
+fn dynamic_shape(shape: &[u64]) -> Result<Vec<usize>, ArrayObjectError> {
+    shape
+        .iter()
+        .map(|&x| {
+            x.try_into()
+                .or(Err(ArrayObjectError::External("Array size exceeds usize.")))
+        })
+        .collect()
+}
+
+impl ArrayObject {
+    fn check_native<T: NativeElement>(&self) -> Result<Vec<usize>, ArrayObjectError> {
+        if cfg!(target_endian = "big") {
+            return Err(ArrayObjectError::External(
+                "Zero-copy views are only available on little-endian targets.",
+            ));
+        }
+        if self.datatype != T::DATATYPE {
+            return Err(ArrayObjectError::WrongDataType(
+                self.datatype.clone(),
+                self.shape.len(),
+            ));
+        }
+        if self.data.len() != self.len() * size_of::<T>() {
+            return Err(ArrayObjectError::External(
+                "The element width does not match the requested type.",
+            ));
+        }
+        if !self.data.is_empty() && self.data.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(ArrayObjectError::External(
+                "The buffer is not aligned for the requested type.",
+            ));
+        }
+        dynamic_shape(&self.shape)
+    }
+    /// Returns a view into the buffer without copying. The elements have to be stored at the native width of `T`, which is the case after `unpack` when the width was not reduced.
+    pub fn as_array_view<T: NativeElement>(&self) -> Result<ArrayViewD<'_, T>, ArrayObjectError> {
+        let shape = self.check_native::<T>()?;
+        let slice: &[T] = if self.data.is_empty() {
+            &[]
+        } else {
+            // SAFETY: the length and the alignment are checked above, and `T` accepts any bit pattern.
+            unsafe {
+                std::slice::from_raw_parts(self.data.as_ptr() as *const T, self.data.len() / size_of::<T>())
+            }
+        };
+        ArrayViewD::from_shape(IxDyn(&shape), slice)
+            .or(Err(ArrayObjectError::External("Array dimension mismatch.")))
+    }
+    /// Returns a mutable view into the buffer without copying. See [`ArrayObject::as_array_view`] for the conditions.
+    pub fn as_array_view_mut<T: NativeElement>(
+        &mut self,
+    ) -> Result<ArrayViewMutD<'_, T>, ArrayObjectError> {
+        let shape = self.check_native::<T>()?;
+        let slice: &mut [T] = if self.data.is_empty() {
+            &mut []
+        } else {
+            // SAFETY: the length and the alignment are checked above, and `T` accepts any bit pattern.
+            unsafe {
+                std::slice::from_raw_parts_mut(
+                    self.data.as_mut_ptr() as *mut T,
+                    self.data.len() / size_of::<T>(),
+                )
+            }
+        };
+        ArrayViewMutD::from_shape(IxDyn(&shape), slice)
+            .or(Err(ArrayObjectError::External("Array dimension mismatch.")))
+    }
+}
+
+#[cfg(feature = "memmap2")]
+impl crate::external::MmapObject {
+    /// Returns a view into the mapping without copying. See [`MmapObject::as_slice`](crate::MmapObject::as_slice) for the conditions.
+    pub fn as_array_view<T: NativeElement>(&self) -> Result<ArrayViewD<'_, T>, ArrayObjectError> {
+        let slice = self.as_slice::<T>()?;
+        ArrayViewD::from_shape(IxDyn(&self.shape()?), slice)
+            .or(Err(ArrayObjectError::External("Array dimension mismatch.")))
+    }
+}
+
+fn restore<T, D: Dimension>(VecShape(data, shape): VecShape<T>) -> Result<Array<T, D>, ArrayObjectError> {
+    let shape = dynamic_shape(&shape)?;
+    Array::from_shape_vec(IxDyn(&shape), data)
+        .or(Err(ArrayObjectError::External("Array dimension mismatch.")))?
+        .into_dimensionality::<D>()
+        .or(Err(ArrayObjectError::External("Array dimension mismatch.")))
+}
+
+impl<A, S, D> TryFrom<ArrayBase<S, D>> for ArrayObject
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+    VecShape<A>: TryInto<ArrayObject, Error = ArrayObjectError>,
+{
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayBase<S, D>) -> Result<Self, Self::Error> {
+        (&val).try_into()
+    }
+}
+
+impl<A, S, D> TryFrom<&ArrayBase<S, D>> for ArrayObject
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+    VecShape<A>: TryInto<ArrayObject, Error = ArrayObjectError>,
+{
+    type Error = ArrayObjectError;
+    fn try_from(val: &ArrayBase<S, D>) -> Result<Self, Self::Error> {
+        let shape: Vec<_> = val.shape().iter().map(|x| *x as u64).collect();
+        let v: Vec<_> = val.iter().cloned().collect();
+        VecShape(v, shape).try_into()
+    }
+}
+
 macro_rules! ndarray_impl {
     ($($ty:ty),*) => {
         $(
-            impl<D: Dimension> TryFrom<Array<$ty, D>> for ArrayObject {
-                type Error = ArrayObjectError;
-                fn try_from(val: Array<$ty, D>) -> Result<Self, Self::Error> {
-                    let shape: Vec<_> = val.shape().iter().map(|x| *x as u64).collect();
-                    let v: Vec<_> = val.into_iter().collect();
-                    VecShape(v, shape).try_into()
-                }
-            }
-            impl<D: Dimension> TryFrom<ArrayView<'_,$ty, D>> for ArrayObject {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayView<'_,$ty, D>) -> Result<Self, Self::Error> {
-                    let shape: Vec<_> = val.shape().iter().map(|x| *x as u64).collect();
-                    let v: Vec<_> = val.to_owned().into_iter().collect();
-                    VecShape(v, shape).try_into()
-                }
-            }
-            impl<D: Dimension> TryFrom<&Array<$ty, D>> for ArrayObject {
-                type Error = ArrayObjectError;
-                fn try_from(val: &Array<$ty, D>) -> Result<Self, Self::Error> {
-                    let shape: Vec<_> = val.shape().iter().map(|x| *x as u64).collect();
-                    let v: Vec<_> = val.to_owned().into_iter().collect();
-                    VecShape(v, shape).try_into()
-                }
-            }
-            impl TryFrom<ArrayObject> for ArrayD<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    let temp = Array::from_shape_vec(shape.as_slice(), data).unwrap();
-                    Ok(temp)
-                }
-            }
-            impl TryFrom<ArrayObject> for Array0<$ty> {
+            impl<D: Dimension> TryFrom<ArrayObject> for Array<$ty, D> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(mut data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.is_empty() {
-                        let temp = Array0::from_elem([], data.pop().unwrap());
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
+                    restore(val.try_into()?)
                 }
             }
-            impl TryFrom<ArrayObject> for Array1<$ty> {
+            impl<D: Dimension> TryFrom<ArrayObject> for ArcArray<$ty, D> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 1 {
-                        let temp = Array1::from_shape_vec(shape[0], data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
+                    Ok(restore(val.try_into()?)?.into_shared())
                 }
             }
-            impl TryFrom<ArrayObject> for Array2<$ty> {
+            impl<D: Dimension> TryFrom<ArrayObject> for CowArray<'_, $ty, D> {
                 type Error = ArrayObjectError;
                 fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 2 {
-                        let temp = Array2::from_shape_vec((shape[0], shape[1]), data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
-                }
-            }
-            impl TryFrom<ArrayObject> for Array3<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 3 {
-                        let temp = Array3::from_shape_vec((shape[0], shape[1], shape[2]), data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
-                }
-            }
-            impl TryFrom<ArrayObject> for Array4<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 4 {
-                        let temp = Array4::from_shape_vec((shape[0], shape[1], shape[2], shape[3]), data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
-                }
-            }
-            impl TryFrom<ArrayObject> for Array5<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 5 {
-                        let temp = Array5::from_shape_vec((shape[0], shape[1], shape[2], shape[3], shape[4]), data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
-                }
-            }
-            impl TryFrom<ArrayObject> for Array6<$ty> {
-                type Error = ArrayObjectError;
-                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
-                    let VecShape::<$ty>(data, shape) = val.try_into()?;
-                    let shape: Vec<usize> = shape.iter().map(|&x| x.try_into().unwrap()).collect();
-                    if shape.len() == 6 {
-                        let temp = Array6::from_shape_vec((shape[0], shape[1], shape[2], shape[3], shape[4], shape[5]), data).unwrap();
-                        Ok(temp)
-                    } else {
-                        Err(ArrayObjectError::External("Array dimension mismatch."))
-                    }
+                    Ok(restore(val.try_into()?)?.into())
                 }
             }
         )*
@@ -165,4 +180,10 @@ impl TryConcat for Array1<ArrayObject> {
     fn try_concat(self) -> Result<ArrayObject, ArrayObjectError> {
         self.to_vec().try_concat()
     }
+    fn concat(self, axis: usize) -> Result<ArrayObject, ArrayObjectError> {
+        self.to_vec().concat(axis)
+    }
+    fn stack(self, axis: usize) -> Result<ArrayObject, ArrayObjectError> {
+        self.to_vec().stack(axis)
+    }
 }
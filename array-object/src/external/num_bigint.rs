@@ -0,0 +1,157 @@
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+
+fn zigzag(x: &BigInt) -> BigUint {
+    match x.sign() {
+        Sign::Minus => (x.magnitude() << 1u32) - 1u32,
+        _ => x.magnitude() << 1u32,
+    }
+}
+
+fn straight(z: BigUint) -> BigInt {
+    if z.bit(0) {
+        BigInt::from_biguint(Sign::Minus, (z + 1u32) >> 1u32)
+    } else {
+        BigInt::from_biguint(Sign::Plus, z >> 1u32)
+    }
+}
+
+/// Stores the integers with the smallest power-of-two size that holds all the elements.
+fn write_wide(val: Vec<BigUint>, shape: Vec<u64>, datatype: DataType) -> ArrayObject {
+    let bytes: Vec<Vec<u8>> = val.iter().map(|x| x.to_bytes_le()).collect();
+    let size = bytes
+        .iter()
+        .map(|x| x.len())
+        .max()
+        .unwrap_or(1)
+        .next_power_of_two();
+    let data = bytes
+        .into_iter()
+        .flat_map(|mut x| {
+            x.resize(size, 0);
+            x
+        })
+        .collect();
+    ArrayObject {
+        data,
+        shape,
+        datatype,
+        validity: None,
+        unit: None,
+    }
+}
+
+fn read_wide(val: ArrayObject) -> Result<VecShape<BigInt>, ArrayObjectError> {
+    let val = val.into_valid()?;
+    let len = val.len();
+    let size = val.data.len().checked_div(len).unwrap_or(1);
+    let data = val.data.chunks(size).map(BigUint::from_bytes_le);
+    let data = match val.datatype {
+        DataType::UnsignedInteger => data.map(BigInt::from).collect(),
+        DataType::SignedInteger => data.map(straight).collect(),
+        _ => {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+    };
+    Ok(VecShape(data, val.shape))
+}
+
+fn into_biguint(val: BigInt) -> Result<BigUint, ArrayObjectError> {
+    let bits = 8 * (zigzag(&val).to_bytes_le().len().next_power_of_two());
+    val.to_biguint()
+        .ok_or(ArrayObjectError::IncompatibleConversion(true, bits))
+}
+
+macro_rules! bigint_impl {
+    ($ty:ty, $datatype:expr, $into:expr, $from:expr) => {
+        impl From<$ty> for ArrayObject {
+            fn from(val: $ty) -> Self {
+                write_wide(vec![$into(&val)], vec![], $datatype)
+            }
+        }
+        impl From<Vec<$ty>> for ArrayObject {
+            fn from(val: Vec<$ty>) -> Self {
+                let shape = vec![val.len() as u64];
+                write_wide(val.iter().map($into).collect(), shape, $datatype)
+            }
+        }
+        impl TryFrom<VecShape<$ty>> for ArrayObject {
+            type Error = ArrayObjectError;
+            fn try_from(VecShape(val, shape): VecShape<$ty>) -> Result<Self, Self::Error> {
+                if val.len() != shape.product() as usize {
+                    return Err(ArrayObjectError::NumberOfElementsMismatch(
+                        val.len(),
+                        shape.product() as usize,
+                    ));
+                }
+                Ok(write_wide(
+                    val.iter().map($into).collect(),
+                    shape,
+                    $datatype,
+                ))
+            }
+        }
+        impl TryFrom<ArrayObject> for $ty {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if !val.shape.is_empty() {
+                    return Err(ArrayObjectError::WrongDataType(
+                        val.datatype,
+                        val.shape.len(),
+                    ));
+                }
+                let VecShape(data, _) = read_wide(val)?;
+                let x = data
+                    .into_iter()
+                    .next()
+                    .ok_or(ArrayObjectError::UnableToDecode)?;
+                $from(x)
+            }
+        }
+        impl TryFrom<ArrayObject> for VecShape<$ty> {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if val.shape.is_empty() {
+                    return Err(ArrayObjectError::WrongDataType(val.datatype, 0));
+                }
+                let VecShape(data, shape) = read_wide(val)?;
+                let data = data.into_iter().map($from).collect::<Result<Vec<_>, _>>()?;
+                Ok(VecShape(data, shape))
+            }
+        }
+        impl TryFrom<ArrayObject> for Vec<$ty> {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if val.shape.len() != 1 {
+                    return Err(ArrayObjectError::WrongDataType(
+                        val.datatype,
+                        val.shape.len(),
+                    ));
+                }
+                let VecShape::<$ty>(data, _) = val.try_into()?;
+                Ok(data)
+            }
+        }
+    };
+}
+
+bigint_impl!(
+    BigUint,
+    DataType::UnsignedInteger,
+    |x: &BigUint| x.clone(),
+    into_biguint
+);
+
+bigint_impl!(
+    BigInt,
+    DataType::SignedInteger,
+    zigzag,
+    |x: BigInt| -> Result<BigInt, ArrayObjectError> { Ok(x) }
+);
@@ -0,0 +1,161 @@
+use rust_decimal::Decimal;
+
+use crate::adaptor::*;
+use crate::convert::cast::{narrow_integer, read_signed, read_unsigned, write_signed};
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::Unit;
+
+impl ArrayObject {
+    /// Creates an array of decimal numbers from the integers and the common exponent.
+    fn from_decimal(val: Vec<i128>, shape: Vec<u64>, exponent: i32) -> Self {
+        ArrayObject {
+            data: write_signed(val),
+            shape,
+            datatype: DataType::SignedInteger,
+            validity: None,
+            unit: Some(Unit::Decimal(exponent)),
+        }
+    }
+    /// Reads the integers and the exponent of decimal numbers. Integers without a unit are read with the exponent of zero.
+    fn into_decimal(self) -> Result<(VecShape<i128>, i32), ArrayObjectError> {
+        let val = narrow_integer(self.into_valid()?)?;
+        let exponent = match (&val.datatype, val.unit) {
+            (DataType::UnsignedInteger | DataType::SignedInteger, None) => 0,
+            (DataType::SignedInteger, Some(Unit::Decimal(exponent))) => exponent,
+            (DataType::UnsignedInteger | DataType::SignedInteger, found) => {
+                return Err(ArrayObjectError::WrongUnit(found, Unit::Decimal(0)));
+            }
+            _ => {
+                return Err(ArrayObjectError::WrongDataType(
+                    val.datatype,
+                    val.shape.len(),
+                ));
+            }
+        };
+        let data = match val.datatype {
+            DataType::UnsignedInteger => read_unsigned(&val)
+                .into_iter()
+                .map(|x| {
+                    i128::try_from(x).or(Err(ArrayObjectError::IncompatibleConversion(false, 128)))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => read_signed(&val),
+        };
+        Ok((VecShape(data, val.shape), exponent))
+    }
+}
+
+/// Brings the decimal numbers to the largest scale among them.
+fn into_mantissas(val: &[Decimal]) -> Result<(Vec<i128>, i32), ArrayObjectError> {
+    let scale = val.iter().map(|x| x.scale()).max().unwrap_or(0);
+    let data = val
+        .iter()
+        .map(|x| {
+            10i128
+                .checked_pow(scale - x.scale())
+                .and_then(|p| x.mantissa().checked_mul(p))
+                .ok_or(ArrayObjectError::Overflow)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((data, -(scale as i32)))
+}
+
+/// Restores a decimal number, dropping trailing zeros if the scale is larger than the maximum.
+fn from_mantissa(mut mantissa: i128, exponent: i32) -> Result<Decimal, ArrayObjectError> {
+    let mut scale = if exponent > 0 {
+        mantissa = 10i128
+            .checked_pow(exponent as u32)
+            .and_then(|p| mantissa.checked_mul(p))
+            .ok_or(ArrayObjectError::Overflow)?;
+        0
+    } else {
+        exponent.unsigned_abs()
+    };
+    while scale > Decimal::MAX_SCALE && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    if scale > Decimal::MAX_SCALE {
+        return Err(ArrayObjectError::LossyConversion);
+    }
+    Decimal::try_from_i128_with_scale(mantissa, scale).or(Err(ArrayObjectError::Overflow))
+}
+
+impl TryFrom<Decimal> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(val: Decimal) -> Result<Self, Self::Error> {
+        let (data, exponent) = into_mantissas(&[val])?;
+        Ok(ArrayObject::from_decimal(data, vec![], exponent))
+    }
+}
+
+impl TryFrom<Vec<Decimal>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(val: Vec<Decimal>) -> Result<Self, Self::Error> {
+        let (data, exponent) = into_mantissas(&val)?;
+        Ok(ArrayObject::from_decimal(
+            data,
+            vec![val.len() as u64],
+            exponent,
+        ))
+    }
+}
+
+impl TryFrom<VecShape<Decimal>> for ArrayObject {
+    type Error = ArrayObjectError;
+    fn try_from(VecShape(val, shape): VecShape<Decimal>) -> Result<Self, Self::Error> {
+        if val.len() != shape.product() as usize {
+            return Err(ArrayObjectError::NumberOfElementsMismatch(
+                val.len(),
+                shape.product() as usize,
+            ));
+        }
+        let (data, exponent) = into_mantissas(&val)?;
+        Ok(ArrayObject::from_decimal(data, shape, exponent))
+    }
+}
+
+impl TryFrom<ArrayObject> for Decimal {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        if !val.shape.is_empty() {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+        let (VecShape(data, _), exponent) = val.into_decimal()?;
+        from_mantissa(data[0], exponent)
+    }
+}
+
+impl TryFrom<ArrayObject> for VecShape<Decimal> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        if val.shape.is_empty() {
+            return Err(ArrayObjectError::WrongDataType(val.datatype, 0));
+        }
+        let (VecShape(data, shape), exponent) = val.into_decimal()?;
+        let data = data
+            .into_iter()
+            .map(|x| from_mantissa(x, exponent))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(VecShape(data, shape))
+    }
+}
+
+impl TryFrom<ArrayObject> for Vec<Decimal> {
+    type Error = ArrayObjectError;
+    fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+        if val.shape.len() != 1 {
+            return Err(ArrayObjectError::WrongDataType(
+                val.datatype,
+                val.shape.len(),
+            ));
+        }
+        let VecShape::<Decimal>(data, _) = val.try_into()?;
+        Ok(data)
+    }
+}
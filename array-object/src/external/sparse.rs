@@ -0,0 +1,93 @@
+use crate::error::ArrayObjectError;
+use crate::pack::Unpack;
+use crate::pack::pack_sparse::{COO, write_sparse};
+use crate::storage::ArrayObject;
+use num_complex::Complex;
+
+/// The values of sparse matrices. The entries at the same position are added up.
+pub trait SparseValue: Copy {
+    /// Returns the sum, or None on overflow.
+    fn accumulate(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SparseValue for $ty {
+                fn accumulate(self, other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SparseValue for $ty {
+                fn accumulate(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+            }
+        )*
+    };
+}
+
+integer_value!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+float_value!(f32, f64, Complex<f32>, Complex<f64>);
+
+/// Encodes (row, column, value) triplets with the sparse extension and restores the object from it, so that the size of the dense array is checked before it is allocated.
+pub fn from_triplets<T, I>(
+    nrows: usize,
+    ncols: usize,
+    triplets: I,
+) -> Result<ArrayObject, ArrayObjectError>
+where
+    T: SparseValue,
+    I: Iterator<Item = (usize, usize, T)>,
+    ArrayObject: From<Vec<T>>,
+{
+    let mut triplets: Vec<_> = triplets.collect();
+    triplets.sort_by_key(|&(i, j, _)| (i, j));
+    let mut entries: Vec<(usize, usize, T)> = Vec::with_capacity(triplets.len());
+    for (i, j, v) in triplets {
+        match entries.last_mut() {
+            Some(last) if (last.0, last.1) == (i, j) => {
+                last.2 = last.2.accumulate(v).ok_or(ArrayObjectError::Overflow)?;
+            }
+            _ => entries.push((i, j, v)),
+        }
+    }
+    let values: ArrayObject = entries.iter().map(|x| x.2).collect::<Vec<_>>().into();
+    let indices = entries
+        .iter()
+        .flat_map(|&(i, j, _)| [i as u64, j as u64])
+        .collect();
+    ArrayObject::unpack(write_sparse(
+        values,
+        COO,
+        indices,
+        &[nrows as u64, ncols as u64],
+    ))
+}
+
+/// Returns (rows, columns) of a 2-D object.
+pub fn matrix_shape(shape: &[u64]) -> Result<(usize, usize), ArrayObjectError> {
+    if shape.len() != 2 {
+        return Err(ArrayObjectError::External("The data is not a matrix"));
+    }
+    Ok((shape[0] as usize, shape[1] as usize))
+}
+
+/// Returns the (row, column, value) triplets of the non-zero elements in row-major order.
+pub fn triplets<T: PartialEq + Default>(data: Vec<T>, ncols: usize) -> Vec<(usize, usize, T)> {
+    let zero = T::default();
+    data.into_iter()
+        .enumerate()
+        .filter(|(_, v)| *v != zero)
+        .map(|(k, v)| (k / ncols, k % ncols, v))
+        .collect()
+}
@@ -0,0 +1,84 @@
+use num_complex::Complex;
+use sprs::{CsMat, TriMat};
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::ArrayObject;
+use crate::external::sparse::*;
+
+fn into_trimat<T: Clone + PartialEq + Default>(val: ArrayObject) -> Result<TriMat<T>, ArrayObjectError>
+where
+    VecShape<T>: TryFrom<ArrayObject, Error = ArrayObjectError>,
+{
+    let VecShape::<T>(data, shape) = val.try_into()?;
+    let (nrows, ncols) = matrix_shape(&shape)?;
+    let mut tri = TriMat::new((nrows, ncols));
+    for (i, j, v) in triplets(data, ncols) {
+        tri.add_triplet(i, j, v);
+    }
+    Ok(tri)
+}
+
+macro_rules! sprs_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<&TriMat<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: &TriMat<$ty>) -> Result<Self, Self::Error> {
+                    let triplets = val.triplet_iter().map(|(v, (i, j))| (i, j, *v));
+                    from_triplets(val.rows(), val.cols(), triplets)
+                }
+            }
+            impl TryFrom<TriMat<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: TriMat<$ty>) -> Result<Self, Self::Error> {
+                    (&val).try_into()
+                }
+            }
+            impl TryFrom<&CsMat<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: &CsMat<$ty>) -> Result<Self, Self::Error> {
+                    let triplets = val.iter().map(|(v, (i, j))| (i, j, *v));
+                    from_triplets(val.rows(), val.cols(), triplets)
+                }
+            }
+            impl TryFrom<CsMat<$ty>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(val: CsMat<$ty>) -> Result<Self, Self::Error> {
+                    (&val).try_into()
+                }
+            }
+            impl TryFrom<ArrayObject> for TriMat<$ty> {
+                type Error = ArrayObjectError;
+                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    into_trimat(val)
+                }
+            }
+            impl TryFrom<ArrayObject> for CsMat<$ty> {
+                type Error = ArrayObjectError;
+                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    Ok(into_trimat::<$ty>(val)?.to_csr())
+                }
+            }
+        )*
+    };
+}
+
+sprs_impl!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    Complex<f32>,
+    Complex<f64>
+);
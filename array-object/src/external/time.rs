@@ -0,0 +1,37 @@
+use time::{Date, Duration, OffsetDateTime};
+
+use crate::adaptor::*;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::storage::*;
+use crate::unit::*;
+
+/// The Julian day of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+time_impl!(
+    OffsetDateTime,
+    Unit::Timestamp(TimeUnit::Nanosecond),
+    |x: &OffsetDateTime| x.unix_timestamp_nanos(),
+    |x: i128| OffsetDateTime::from_unix_timestamp_nanos(x).ok()
+);
+
+time_impl!(
+    Date,
+    Unit::Date,
+    |x: &Date| (x.to_julian_day() - UNIX_EPOCH_JULIAN_DAY) as i128,
+    |x: i128| {
+        let day = i32::try_from(x).ok()?.checked_add(UNIX_EPOCH_JULIAN_DAY)?;
+        Date::from_julian_day(day).ok()
+    }
+);
+
+time_impl!(
+    Duration,
+    Unit::Duration(TimeUnit::Nanosecond),
+    |x: &Duration| x.whole_nanoseconds(),
+    |x: i128| {
+        let secs = i64::try_from(x / 1_000_000_000).ok()?;
+        Some(Duration::new(secs, (x % 1_000_000_000) as i32))
+    }
+);
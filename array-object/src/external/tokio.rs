@@ -0,0 +1,72 @@
+use std::io::SeekFrom;
+use std::path::Path;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+use tokio::task::spawn_blocking;
+
+use crate::error::ArrayObjectError;
+use crate::pack::{Pack, Unpack};
+use crate::storage::ArrayObject;
+
+/// Runs the closure on the blocking thread pool of tokio.
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T, ArrayObjectError> + Send + 'static,
+) -> Result<T, ArrayObjectError> {
+    spawn_blocking(f).await.map_err(std::io::Error::other)?
+}
+
+impl ArrayObject {
+    /// Compresses the data on the blocking thread pool, so that a large array does not stall the executor.
+    pub async fn pack_blocking(self) -> Result<Vec<u8>, ArrayObjectError> {
+        blocking(move || Ok(self.pack())).await
+    }
+    /// Restores the object on the blocking thread pool, so that a large array does not stall the executor.
+    pub async fn unpack_blocking(data: Vec<u8>) -> Result<ArrayObject, ArrayObjectError> {
+        blocking(move || ArrayObject::unpack(data)).await
+    }
+    /// Compresses the data and writes it into the writer.
+    pub async fn pack_to_async<W: AsyncWrite + Unpin>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), ArrayObjectError> {
+        let data = self.pack_blocking().await?;
+        writer.write_all(&data).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+    /// Reads the binary from the current position to the end of the reader and restores the object.
+    pub async fn unpack_from_async<R: AsyncRead + AsyncSeek + Unpin>(
+        reader: &mut R,
+    ) -> Result<ArrayObject, ArrayObjectError> {
+        let start = reader.stream_position().await?;
+        let end = reader.seek(SeekFrom::End(0)).await?;
+        reader.seek(SeekFrom::Start(start)).await?;
+        let len = end
+            .checked_sub(start)
+            .ok_or(ArrayObjectError::UnableToDecode)?;
+        let mut data = vec![0; len as usize];
+        reader.read_exact(&mut data).await?;
+        ArrayObject::unpack_blocking(data).await
+    }
+    /// Writes the data into the file on the blocking thread pool in the same way as [`ArrayObject::save`].
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// runtime.block_on(async {
+    ///     let obj: ArrayObject = vec![1u8, 2, 3].into();
+    ///     let path = std::env::temp_dir().join("save_async.bin");
+    ///     obj.clone().save_async(&path).await.unwrap();
+    ///     assert_eq!(ArrayObject::load_async(&path).await.unwrap(), obj);
+    /// });
+    /// ```
+    pub async fn save_async(self, path: impl AsRef<Path>) -> Result<(), ArrayObjectError> {
+        let path = path.as_ref().to_path_buf();
+        blocking(move || self.save(path)).await
+    }
+    /// Reads the file and restores the object on the blocking thread pool.
+    pub async fn load_async(path: impl AsRef<Path>) -> Result<ArrayObject, ArrayObjectError> {
+        let path = path.as_ref().to_path_buf();
+        blocking(move || ArrayObject::load(path)).await
+    }
+}
@@ -0,0 +1,141 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::ArrayObjectError;
+use crate::pack::{Pack, PackOption, Unpack};
+use crate::storage::ArrayObject;
+
+/// Options for `ArrayObject::save_with()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SaveOption {
+    /// The options to create the binary.
+    pub pack: PackOption,
+    /// Read the written file back and check that it restores the same object before it replaces the target.
+    pub verify: bool,
+}
+
+/// A counter to give a unique name to the temporary files created by this process.
+static TEMPORARY: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a path of a temporary file in the same directory as the target, so that it can be renamed atomically.
+fn temporary_path(path: &Path) -> Result<PathBuf, ArrayObjectError> {
+    let name = path.file_name().ok_or(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "The path has no file name.",
+    ))?;
+    let mut temp = std::ffi::OsString::from(".");
+    temp.push(name);
+    temp.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMPORARY.fetch_add(1, Ordering::Relaxed)
+    ));
+    Ok(path.with_file_name(temp))
+}
+
+/// Writes the data into the temporary file and flushes it to the disk.
+fn write_synced(path: &Path, data: &[u8]) -> Result<(), ArrayObjectError> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+/// Reads the written file and checks that it restores the original object.
+fn verify_file(path: &Path, data: &[u8], original: &ArrayObject) -> Result<(), ArrayObjectError> {
+    let written = std::fs::read(path)?;
+    if written != data {
+        return Err(ArrayObjectError::VerificationFailed);
+    }
+    let restored = ArrayObject::unpack(written)?;
+    if restored.unit() != original.unit() || !restored.semantically_eq(original) {
+        return Err(ArrayObjectError::VerificationFailed);
+    }
+    Ok(())
+}
+
+impl ArrayObject {
+    /// Compresses the data and writes it into the file. If the file exists, this will overwrite the existing file.
+    ///
+    /// The data is written into a temporary file in the same directory, which is renamed to the target after it is flushed to the disk, so that the target is never left truncated.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1u8, 2, 3].into();
+    /// let path = std::env::temp_dir().join("save.bin");
+    /// obj.clone().save(&path).unwrap();
+    /// assert_eq!(ArrayObject::load(&path).unwrap(), obj);
+    /// ```
+    pub fn save(self, path: impl AsRef<Path>) -> Result<(), ArrayObjectError> {
+        self.save_with(path, SaveOption::default())
+    }
+    /// Writes the data into the file with the options. See [`ArrayObject::save`] for how the file is replaced.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1.5f64, f64::NAN].into();
+    /// let path = std::env::temp_dir().join("save_with.bin");
+    /// let option = SaveOption { verify: true, ..Default::default() };
+    /// obj.save_with(&path, option).unwrap();
+    /// ```
+    pub fn save_with(
+        self,
+        path: impl AsRef<Path>,
+        option: SaveOption,
+    ) -> Result<(), ArrayObjectError> {
+        let path = path.as_ref();
+        let original = option.verify.then(|| self.clone());
+        let data = self.pack_with(option.pack);
+        let temp = temporary_path(path)?;
+        let result = write_synced(&temp, &data)
+            .and_then(|_| match &original {
+                Some(original) => verify_file(&temp, &data, original),
+                None => Ok(()),
+            })
+            .and_then(|_| Ok(std::fs::rename(&temp, path)?));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp);
+            return result;
+        }
+        #[cfg(unix)]
+        if let Some(dir) = path.parent() {
+            let dir = if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir
+            };
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+    /// Reads the file and restores the object.
+    pub fn load(path: impl AsRef<Path>) -> Result<ArrayObject, ArrayObjectError> {
+        ArrayObject::unpack(std::fs::read(path)?)
+    }
+    /// Converts the data into an object and writes it into the file.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let path = std::env::temp_dir().join("save_as.bin");
+    /// ArrayObject::save_as(vec![1.5f64, -2.0], &path).unwrap();
+    /// let restored: Vec<f64> = ArrayObject::load_as(&path).unwrap();
+    /// assert_eq!(restored, vec![1.5, -2.0]);
+    /// ```
+    pub fn save_as<T>(val: T, path: impl AsRef<Path>) -> Result<(), ArrayObjectError>
+    where
+        T: TryInto<ArrayObject>,
+        ArrayObjectError: From<T::Error>,
+    {
+        val.try_into()?.save(path)
+    }
+    /// Reads the file and restores the data into `T`.
+    pub fn load_as<T>(path: impl AsRef<Path>) -> Result<T, ArrayObjectError>
+    where
+        T: TryFrom<ArrayObject>,
+        ArrayObjectError: From<T::Error>,
+    {
+        Ok(ArrayObject::load(path)?.try_into()?)
+    }
+}
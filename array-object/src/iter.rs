@@ -0,0 +1,119 @@
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::pack::varint::varint_decode;
+use crate::storage::*;
+use std::marker::PhantomData;
+
+/// An iterator over the elements of ArrayObject, inflating each element into `T`. See `ArrayObject::iter()`.
+pub struct ArrayIter<'a, T> {
+    obj: &'a ArrayObject,
+    pos: usize,
+    index: usize,
+    size: usize,
+    _marker: PhantomData<T>,
+}
+
+impl ArrayObject {
+    /// Creates an ArrayObject from an iterator and the shape. The number of elements should agree with the shape.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj = ArrayObject::from_iter_shape((0..6).map(|i| i as f64), vec![2, 3]).unwrap();
+    /// assert_eq!(obj.shape(), vec![2, 3]);
+    /// ```
+    pub fn from_iter_shape<T, I>(iter: I, shape: Vec<u64>) -> Result<Self, ArrayObjectError>
+    where
+        I: IntoIterator<Item = T>,
+        ArrayObject: FromIterator<T>,
+    {
+        let mut obj: ArrayObject = iter.into_iter().collect();
+        if obj.shape[0] != shape.product() {
+            return Err(ArrayObjectError::NumberOfElementsMismatch(
+                obj.shape[0] as usize,
+                shape.product() as usize,
+            ));
+        }
+        obj.shape = shape;
+        Ok(obj)
+    }
+    /// Returns an iterator over the elements in row major order. Each element is inflated into `T` in the same way as a single value.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1u8, 2, 3].into();
+    /// let sum: u32 = obj.iter::<u32>().map(|x| x.unwrap()).sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter<T: TryFrom<ArrayObject, Error = ArrayObjectError>>(&self) -> ArrayIter<'_, T> {
+        let size = match (self.len(), &self.datatype) {
+            (0, _) | (_, DataType::String | DataType::Bytes) => 0,
+            (len, _) => self.data.len() / len,
+        };
+        ArrayIter {
+            obj: self,
+            pos: 0,
+            index: 0,
+            size,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Collects the elements into a one-dimensional array, in the same way as `From<Vec<T>>`.
+impl<T> FromIterator<T> for ArrayObject
+where
+    ArrayObject: From<Vec<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<T>>().into()
+    }
+}
+
+impl<T: TryFrom<ArrayObject, Error = ArrayObjectError>> Iterator for ArrayIter<'_, T> {
+    type Item = Result<T, ArrayObjectError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.obj.len() {
+            return None;
+        }
+        let data = if self.obj.datatype == DataType::String {
+            let len = self.obj.data[self.pos..]
+                .iter()
+                .position(|&x| x == 255)
+                .unwrap_or(self.obj.data.len() - self.pos);
+            let data = self.obj.data[self.pos..self.pos + len].to_vec();
+            self.pos += len + 1;
+            data
+        } else if self.obj.datatype == DataType::Bytes {
+            let (n, k) = varint_decode(self.obj.data[self.pos..].iter(), 1);
+            let end =
+                (self.pos + k + n.first().copied().unwrap_or(0) as usize).min(self.obj.data.len());
+            let data = self.obj.data[self.pos..end].to_vec();
+            self.pos = end;
+            data
+        } else {
+            let data = self.obj.data[self.pos..self.pos + self.size].to_vec();
+            self.pos += self.size;
+            data
+        };
+        let validity = if self.obj.is_valid(self.index) {
+            None
+        } else {
+            Some(vec![0])
+        };
+        self.index += 1;
+        let element = ArrayObject {
+            data,
+            shape: vec![],
+            datatype: self.obj.datatype.clone(),
+            validity,
+            unit: self.obj.unit,
+        };
+        Some(element.try_into())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.obj.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T: TryFrom<ArrayObject, Error = ArrayObjectError>> ExactSizeIterator for ArrayIter<'_, T> {}
@@ -26,11 +26,12 @@
 //!
 //! fn main() {
 //!     // Save into a file
+//!     let path = std::env::temp_dir().join("testdata.bin");
 //!     let original = vec![1f64, 2.2, -1.1, 5.6];
-//!     export_obj!("testdata.bin", original.clone()); // The type has to be known at this point.
+//!     export_obj!(&path, original.clone()); // The type has to be known at this point.
 //!
 //!     // Load from a file
-//!     let restored: Vec<f64> = import_obj!("testdata.bin"); // The type annotation is required.
+//!     let restored: Vec<f64> = import_obj!(&path); // The type annotation is required.
 //!     assert_eq!(original, restored);
 //! }
 //! ```
@@ -38,14 +39,43 @@
 /// Adaptors for Complex and Array. These can be used to restore the data or construct ArrayObject without num::complex, ndarray or nalgebra.
 pub mod adaptor;
 mod bitfield;
+mod compare;
+mod compute;
+#[cfg(any(unix, windows))]
+mod container;
 mod convert;
+mod display;
 mod error;
 mod external;
+mod file;
+mod iter;
 mod misc;
+mod nullable;
 mod pack;
 mod storage;
+mod unit;
 
+pub use compare::Diff;
+#[cfg(any(unix, windows))]
+pub use container::Container;
+pub use convert::CastOption;
+pub use error::ArrayObjectError;
+pub use file::SaveOption;
+pub use display::ArrayDisplay;
+#[cfg(feature = "memmap2")]
+pub use external::MmapObject;
+#[cfg(any(
+    feature = "ndarray_15",
+    feature = "ndarray_16",
+    feature = "ndarray_17",
+    feature = "memmap2"
+))]
+pub use external::NativeElement;
+pub use iter::ArrayIter;
 pub use misc::TryConcat;
 pub use pack::Pack;
+pub use pack::PackOption;
 pub use pack::Unpack;
+pub use pack::UnpackOption;
 pub use storage::{ArrayObject, DataType};
+pub use unit::{TimeUnit, Unit};
@@ -1,5 +1,7 @@
+use crate::convert::from_bytes::join_bytes;
 use crate::error::ArrayObjectError;
-use crate::ArrayObject;
+use crate::nullable::into_bitmap;
+use crate::storage::{ArrayObject, DataType};
 
 pub(crate) trait Product {
     fn product(&self) -> u64;
@@ -21,69 +23,300 @@ impl Product for Vec<u64> {
 ///     objs.push(temp);
 /// }
 /// let obj = objs.try_concat().unwrap();
+/// assert_eq!(obj.shape(), vec![8, 4]);
+///
+/// let a: ArrayObject = vec![0u8, 1].into();
+/// let b: ArrayObject = vec![1000u32, 2000].into();
+/// let obj = vec![a, b].concat(0).unwrap();
+/// assert_eq!(obj.shape(), vec![4]);
 /// ```
 pub trait TryConcat {
-    /// Concatenates ArrayObjects of the same type, size and shape, and create an ArrayObject of one higher dimension.
+    /// Concatenates ArrayObjects of the same type and shape, and create an ArrayObject of one higher dimension.
     fn try_concat(self) -> Result<ArrayObject, ArrayObjectError>;
+    /// Joins ArrayObjects of the same type along an existing axis. The shapes should agree except for the axis.
+    fn concat(self, axis: usize) -> Result<ArrayObject, ArrayObjectError>;
+    /// Joins ArrayObjects of the same type and shape along a new axis.
+    fn stack(self, axis: usize) -> Result<ArrayObject, ArrayObjectError>;
 }
 
 impl TryConcat for Vec<ArrayObject> {
     fn try_concat(self) -> Result<ArrayObject, ArrayObjectError> {
-        if self[0].shape.len() > 14 {
-            return Err(ArrayObjectError::TooLargeDimension(self[0].shape.len() + 1));
+        self.stack(0)
+    }
+    fn concat(mut self, axis: usize) -> Result<ArrayObject, ArrayObjectError> {
+        if self.is_empty() {
+            return Err(ArrayObjectError::EmptyConcat);
         }
-        let shape_orig = self[0].shape.clone();
         let datatype = self[0].datatype.clone();
-        let datasize = self[0].datasize();
+        let shape_orig = self[0].shape.clone();
+        if axis >= shape_orig.len() {
+            return Err(ArrayObjectError::AxisOutOfRange(axis, shape_orig.len()));
+        }
+        for v in self.iter() {
+            if datatype != v.datatype
+                || self[0].unit != v.unit
+                || shape_orig.len() != v.shape.len()
+                || (0..shape_orig.len()).any(|i| i != axis && shape_orig[i] != v.shape[i])
+            {
+                return Err(ArrayObjectError::ConcatShapeMismatch);
+            }
+        }
 
+        let unit = self[0].unit;
         let mut shape = shape_orig.clone();
-        shape.insert(0, self.len() as u64);
+        shape[axis] = self.iter().map(|v| v.shape[axis]).sum();
+        let outer = shape_orig[..axis].iter().product::<u64>() as usize;
+        let inner = shape_orig[axis + 1..].iter().product::<u64>() as usize;
 
-        let mut data = vec![];
-        for mut v in self.into_iter() {
-            if shape_orig == v.shape && datatype == v.datatype && datasize == v.datasize() {
-                data.append(&mut v.data);
-            } else {
-                return Err(ArrayObjectError::ConcatShapeMismatch);
+        let validity = if self.iter().any(|v| v.is_nullable()) {
+            let parts = self
+                .iter()
+                .map(|v| {
+                    let validity = v.validity().unwrap_or_else(|| vec![true; v.len()]);
+                    (validity, v.shape[axis] as usize * inner)
+                })
+                .collect();
+            Some(into_bitmap(&interleave(parts, outer)))
+        } else {
+            None
+        };
+        let data = if matches!(datatype, DataType::String | DataType::Bytes) {
+            let parts = self
+                .iter()
+                .map(|v| Ok((split_elements(v)?, v.shape[axis] as usize * inner)))
+                .collect::<Result<_, ArrayObjectError>>()?;
+            join_elements(&datatype, interleave(parts, outer))
+        } else {
+            let size = self
+                .iter()
+                .filter(|v| v.len() > 0)
+                .map(|v| v.data.len() / v.len())
+                .max()
+                .unwrap_or(0);
+            for v in self.iter_mut() {
+                widen(v, size);
             }
-        }
+            let parts = self
+                .into_iter()
+                .map(|v| {
+                    let n = v.shape[axis] as usize * inner * size;
+                    (v.data, n)
+                })
+                .collect();
+            interleave(parts, outer)
+        };
         Ok(ArrayObject {
             data,
             shape,
             datatype,
+            validity,
+            unit,
         })
     }
+    fn stack(mut self, axis: usize) -> Result<ArrayObject, ArrayObjectError> {
+        if self.is_empty() {
+            return Err(ArrayObjectError::EmptyConcat);
+        }
+        let shape_orig = self[0].shape.clone();
+        if axis > shape_orig.len() {
+            return Err(ArrayObjectError::AxisOutOfRange(axis, shape_orig.len() + 1));
+        }
+        for v in self.iter_mut() {
+            if shape_orig != v.shape {
+                return Err(ArrayObjectError::ConcatShapeMismatch);
+            }
+            v.shape.insert(axis, 1);
+        }
+        self.concat(axis)
+    }
+}
+
+impl ArrayObject {
+    /// Splits the array into equal sections along the axis. This is the inverse of `concat`.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = adaptor::VecShape(vec![0u8, 1, 2, 3, 4, 5], vec![2, 3]).try_into().unwrap();
+    /// let objs = obj.split(0, 2).unwrap();
+    /// assert_eq!(objs[1].shape(), vec![1, 3]);
+    /// ```
+    pub fn split(self, axis: usize, sections: usize) -> Result<Vec<ArrayObject>, ArrayObjectError> {
+        if axis >= self.shape.len() {
+            return Err(ArrayObjectError::AxisOutOfRange(axis, self.shape.len()));
+        }
+        let len_axis = self.shape[axis] as usize;
+        if sections == 0 || !len_axis.is_multiple_of(sections) {
+            return Err(ArrayObjectError::IndivisibleSplit(len_axis, sections));
+        }
+        let outer = self.shape[..axis].iter().product::<u64>() as usize;
+        let inner = self.shape[axis + 1..].iter().product::<u64>() as usize;
+        let n = len_axis / sections * inner;
+
+        let mut shape = self.shape.clone();
+        shape[axis] = (len_axis / sections) as u64;
+        let datatype = self.datatype.clone();
+
+        let validity = match self.validity() {
+            Some(validity) => deinterleave(validity, outer, sections, n)
+                .into_iter()
+                .map(|x| Some(into_bitmap(&x)))
+                .collect(),
+            None => vec![None; sections],
+        };
+        let parts = if matches!(datatype, DataType::String | DataType::Bytes) {
+            deinterleave(split_elements(&self)?, outer, sections, n)
+                .into_iter()
+                .map(|x| join_elements(&datatype, x))
+                .collect()
+        } else {
+            let size = match self.len() {
+                0 => 0,
+                len => self.data.len() / len,
+            };
+            deinterleave(self.data, outer, sections, n * size)
+        };
+        Ok(parts
+            .into_iter()
+            .zip(validity)
+            .map(|(data, validity)| ArrayObject {
+                data,
+                shape: shape.clone(),
+                datatype: datatype.clone(),
+                validity,
+                unit: self.unit,
+            })
+            .collect())
+    }
+    /// Splits the array into slices along the axis and removes the axis. This is the inverse of `stack`.
+    pub fn unstack(self, axis: usize) -> Result<Vec<ArrayObject>, ArrayObjectError> {
+        if axis >= self.shape.len() {
+            return Err(ArrayObjectError::AxisOutOfRange(axis, self.shape.len()));
+        }
+        let len_axis = self.shape[axis] as usize;
+        if len_axis == 0 {
+            return Ok(vec![]);
+        }
+        let mut objs = self.split(axis, len_axis)?;
+        for v in objs.iter_mut() {
+            v.shape.remove(axis);
+        }
+        Ok(objs)
+    }
+}
+
+/// Widens the elements of integer or float data to the given size in bytes.
+fn widen(obj: &mut ArrayObject, size: usize) {
+    let len = obj.len();
+    if len == 0 || obj.data.len() == size * len {
+        return;
+    }
+    let size_orig = obj.data.len() / len;
+    obj.data = match obj.datatype {
+        DataType::Real | DataType::Complex => obj
+            .data
+            .chunks(4)
+            .flat_map(|x| (f32::from_le_bytes(x.try_into().unwrap()) as f64).to_le_bytes())
+            .collect(),
+        _ => obj
+            .data
+            .chunks(size_orig)
+            .flat_map(|x| {
+                x.iter()
+                    .copied()
+                    .chain(std::iter::repeat_n(0, size - size_orig))
+            })
+            .collect(),
+    };
+}
+
+/// Returns the bytes of each element, checking that the number of elements agrees with the shape.
+fn split_elements(obj: &ArrayObject) -> Result<Vec<Vec<u8>>, ArrayObjectError> {
+    match obj.elements() {
+        Some(elements) if elements.len() == obj.len() => {
+            Ok(elements.into_iter().map(|x| x.to_vec()).collect())
+        }
+        _ => Err(ArrayObjectError::UnableToDecode),
+    }
 }
 
-/// A macro to save the data into a file. If the file exists, this will overwrite the existing file.
+fn join_elements(datatype: &DataType, elements: Vec<Vec<u8>>) -> Vec<u8> {
+    match datatype {
+        DataType::Bytes => join_bytes(elements),
+        _ => elements.join(&255u8),
+    }
+}
+
+fn interleave<T: Clone>(parts: Vec<(Vec<T>, usize)>, outer: usize) -> Vec<T> {
+    let mut data = Vec::with_capacity(parts.iter().map(|(v, _)| v.len()).sum());
+    for i in 0..outer {
+        for (v, n) in parts.iter() {
+            data.extend_from_slice(&v[i * n..(i + 1) * n]);
+        }
+    }
+    data
+}
+
+fn deinterleave<T: Clone>(data: Vec<T>, outer: usize, sections: usize, n: usize) -> Vec<Vec<T>> {
+    let mut parts = vec![Vec::with_capacity(outer * n); sections];
+    for i in 0..outer {
+        for (j, part) in parts.iter_mut().enumerate() {
+            let start = (i * sections + j) * n;
+            part.extend_from_slice(&data[start..start + n]);
+        }
+    }
+    parts
+}
+
+/// A macro to save the data into a file. If the file exists, this will overwrite the existing file. This panics on failure; see [`try_export_obj!`] for the fallible version.
 ///
 /// ```
 /// use array_object::*;
 /// let data = vec![1f64, 2.2, -1.1, 5.6];
-/// export_obj!("testdata.bin", data.clone());
+/// export_obj!(std::env::temp_dir().join("export.bin"), data.clone());
 /// ```
 #[macro_export]
 macro_rules! export_obj {
-    ($path:literal,$x:expr) => {{
-        use array_object::Pack;
-        let obj: array_object::ArrayObject = $x.try_into().unwrap();
-        let data = obj.pack();
-        std::fs::write($path, data).unwrap();
+    ($path:expr, $x:expr $(,)?) => {{
+        $crate::ArrayObject::save_as($x, $path).unwrap()
+    }};
+}
+
+/// A macro to save the data into a file, returning `Result<(), ArrayObjectError>`.
+///
+/// ```
+/// use array_object::*;
+/// let path = std::env::temp_dir().join("try_export.bin");
+/// try_export_obj!(&path, vec![1u8, 2, 3]).unwrap();
+/// let restored: Vec<u8> = try_import_obj!(&path).unwrap();
+/// assert_eq!(restored, vec![1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! try_export_obj {
+    ($path:expr, $x:expr $(,)?) => {{
+        $crate::ArrayObject::save_as($x, $path)
     }};
 }
 
-/// A macro to load the data from a file.
+/// A macro to load the data from a file. This panics on failure; see [`try_import_obj!`] for the fallible version.
 ///
 /// ```
 /// use array_object::*;
-/// let restored: Vec<f64> = import_obj!("testdata.bin");
+/// let path = std::env::temp_dir().join("import.bin");
+/// export_obj!(&path, vec![1f64, 2.2]);
+/// let restored: Vec<f64> = import_obj!(&path);
+/// assert_eq!(restored, vec![1.0, 2.2]);
 /// ```
 #[macro_export]
 macro_rules! import_obj {
-    ($path:literal) => {{
-        use array_object::Unpack;
-        let data = std::fs::read($path).unwrap();
-        let obj = array_object::ArrayObject::unpack(data).unwrap();
-        obj.try_into().unwrap()
+    ($path:expr $(,)?) => {{
+        $crate::ArrayObject::load_as($path).unwrap()
+    }};
+}
+
+/// A macro to load the data from a file, returning `Result<T, ArrayObjectError>`.
+#[macro_export]
+macro_rules! try_import_obj {
+    ($path:expr $(,)?) => {{
+        $crate::ArrayObject::load_as($path)
     }};
 }
@@ -0,0 +1,183 @@
+use crate::adaptor::*;
+use crate::convert::from_bytes::join_bytes;
+use crate::error::ArrayObjectError;
+use crate::storage::*;
+use num_complex::Complex;
+
+impl ArrayObject {
+    /// Returns true if the array has a validity mask.
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+    /// Returns the validity of each element in row major order, or None if the array has no validity mask.
+    pub fn validity(&self) -> Option<Vec<bool>> {
+        self.validity
+            .as_ref()
+            .map(|bitmap| from_bitmap(bitmap, self.len()))
+    }
+    /// Returns false if the element at the position in row major order is null.
+    pub fn is_valid(&self, index: usize) -> bool {
+        match &self.validity {
+            Some(bitmap) => bitmap[index / 8] & (1 << (index % 8)) != 0,
+            None => true,
+        }
+    }
+    /// Returns the number of null elements.
+    pub fn null_count(&self) -> usize {
+        match &self.validity {
+            Some(bitmap) => {
+                self.len()
+                    - bitmap
+                        .iter()
+                        .map(|x| x.count_ones() as usize)
+                        .sum::<usize>()
+            }
+            None => 0,
+        }
+    }
+    /// Attaches a validity mask to the array. The null elements are replaced by zero or an empty string.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1u8, 2, 3].into();
+    /// let obj = obj.with_validity(vec![true, false, true]).unwrap();
+    /// assert_eq!(obj.null_count(), 1);
+    /// let restored: Vec<Option<u8>> = obj.try_into().unwrap();
+    /// assert_eq!(restored, vec![Some(1), None, Some(3)]);
+    /// ```
+    pub fn with_validity(mut self, validity: Vec<bool>) -> Result<Self, ArrayObjectError> {
+        let len = self.len();
+        if validity.len() != len {
+            return Err(ArrayObjectError::NumberOfElementsMismatch(
+                validity.len(),
+                len,
+            ));
+        }
+        if let Some(elements) = self.elements() {
+            let elements: Vec<_> = elements
+                .into_iter()
+                .zip(&validity)
+                .map(|(x, &valid)| if valid { x.to_vec() } else { vec![] })
+                .collect();
+            self.data = match self.datatype {
+                DataType::Bytes => join_bytes(elements),
+                _ => elements.join(&255u8),
+            };
+        } else if let Some(size) = self.data.len().checked_div(len) {
+            for (x, &valid) in self.data.chunks_mut(size).zip(&validity) {
+                if !valid {
+                    x.fill(0);
+                }
+            }
+        }
+        self.validity = Some(into_bitmap(&validity));
+        Ok(self)
+    }
+    /// Drops the validity mask, which is allowed only when no element is null.
+    pub(crate) fn into_valid(mut self) -> Result<Self, ArrayObjectError> {
+        if self.null_count() > 0 {
+            return Err(ArrayObjectError::NullElement);
+        }
+        self.validity = None;
+        Ok(self)
+    }
+}
+
+/// Packs the validity into bits, the first element in the least significant bit.
+pub(crate) fn into_bitmap(validity: &[bool]) -> Vec<u8> {
+    validity
+        .chunks(8)
+        .map(|x| {
+            x.iter()
+                .enumerate()
+                .fold(0u8, |a, (i, &valid)| a | ((valid as u8) << i))
+        })
+        .collect()
+}
+
+pub(crate) fn from_bitmap(bitmap: &[u8], len: usize) -> Vec<bool> {
+    (0..len)
+        .map(|i| bitmap[i / 8] & (1 << (i % 8)) != 0)
+        .collect()
+}
+
+fn split_validity<T: Default>(val: Vec<Option<T>>) -> (Vec<T>, Vec<u8>) {
+    let validity: Vec<bool> = val.iter().map(|x| x.is_some()).collect();
+    let data = val.into_iter().map(|x| x.unwrap_or_default()).collect();
+    (data, into_bitmap(&validity))
+}
+
+fn join_validity<T>(data: Vec<T>, validity: Option<Vec<bool>>) -> Vec<Option<T>> {
+    match validity {
+        Some(validity) => data
+            .into_iter()
+            .zip(validity)
+            .map(|(x, valid)| if valid { Some(x) } else { None })
+            .collect(),
+        None => data.into_iter().map(Some).collect(),
+    }
+}
+
+macro_rules! nullable_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl From<Vec<Option<$ty>>> for ArrayObject {
+                fn from(val: Vec<Option<$ty>>) -> Self {
+                    let (data, validity) = split_validity(val);
+                    let mut obj: ArrayObject = data.into();
+                    obj.validity = Some(validity);
+                    obj
+                }
+            }
+            impl TryFrom<VecShape<Option<$ty>>> for ArrayObject {
+                type Error = ArrayObjectError;
+                fn try_from(VecShape(val, shape): VecShape<Option<$ty>>) -> Result<Self, Self::Error> {
+                    let (data, validity) = split_validity(val);
+                    let mut obj: ArrayObject = VecShape(data, shape).try_into()?;
+                    obj.validity = Some(validity);
+                    Ok(obj)
+                }
+            }
+            impl TryFrom<ArrayObject> for VecShape<Option<$ty>> {
+                type Error = ArrayObjectError;
+                fn try_from(mut val: ArrayObject) -> Result<Self, Self::Error> {
+                    let validity = val.validity();
+                    val.validity = None;
+                    let VecShape::<$ty>(data, shape) = val.try_into()?;
+                    Ok(VecShape(join_validity(data, validity), shape))
+                }
+            }
+            impl TryFrom<ArrayObject> for Vec<Option<$ty>> {
+                type Error = ArrayObjectError;
+                fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                    if val.shape.len() != 1 {
+                        return Err(ArrayObjectError::WrongDataType(val.datatype, val.shape.len()));
+                    }
+                    let VecShape::<Option<$ty>>(data, _) = val.try_into()?;
+                    Ok(data)
+                }
+            }
+        )*
+    };
+}
+
+nullable_impl!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    Complex<f32>,
+    Complex<f64>,
+    String,
+    Vec<u8>
+);
@@ -1,12 +1,24 @@
 mod pack;
+mod pack_bytes;
+mod pack_checksum;
+mod pack_dimension;
 mod pack_float;
 mod pack_integer;
+mod pack_nullable;
+pub(crate) mod pack_sparse;
 mod pack_string;
+mod pack_unit;
 mod unpack;
+mod unpack_bytes;
+mod unpack_checksum;
+mod unpack_dimension;
 mod unpack_float;
 mod unpack_integer;
+mod unpack_nullable;
+mod unpack_sparse;
 mod unpack_string;
-mod varint;
+mod unpack_unit;
+pub(crate) mod varint;
 
-pub use pack::Pack;
-pub use unpack::Unpack;
+pub use pack::{Pack, PackOption};
+pub use unpack::{Unpack, UnpackOption};
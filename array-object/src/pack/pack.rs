@@ -1,183 +1,102 @@
 use crate::bitfield::*;
 use crate::misc::Product;
+use crate::pack::pack_bytes::*;
+use crate::pack::pack_checksum::*;
+use crate::pack::pack_dimension::*;
 use crate::pack::pack_float::*;
 use crate::pack::pack_integer::*;
+use crate::pack::pack_nullable::*;
+use crate::pack::pack_sparse::*;
 use crate::pack::pack_string::*;
+use crate::pack::pack_unit::*;
 use crate::pack::varint::*;
 use crate::storage::*;
 
+/// Options for `Pack::pack_with()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackOption {
+    /// Compress the data as `pack()` does. Otherwise the data is stored as `pack_as_it_is()` does.
+    pub compress: bool,
+    /// Append the CRC-32C checksum of the binary, which is verified by `unpack()`.
+    pub checksum: bool,
+}
+
+impl Default for PackOption {
+    fn default() -> Self {
+        Self {
+            compress: true,
+            checksum: false,
+        }
+    }
+}
+
 /// Convert into binary.
 pub trait Pack {
     /// Compress the data and create a binary object.
     fn pack(self) -> Vec<u8>;
     /// Create a binary object without compression.
     fn pack_as_it_is(self) -> Vec<u8>;
+    /// Create a binary object with the options.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![1.5f64, 2.5].into();
+    /// let option = PackOption { checksum: true, ..Default::default() };
+    /// let binary = obj.pack_with(option);
+    /// assert_eq!(DataType::describe_footer(&binary), "Array with checksum");
+    /// ```
+    fn pack_with(self, option: PackOption) -> Vec<u8>;
 }
 
 impl Pack for ArrayObject {
-    fn pack(self) -> Vec<u8> {
-        match self.datatype {
-            DataType::UnsignedInteger => {
-                let len = self.shape.product();
-                if len == 0 {
-                    let mut data = vec![];
-                    let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    return data;
-                }
-                let size_orig = self.data.len() / len as usize;
-                match inspect_integer(&self.data, size_orig, &self.shape) {
-                    IntegerPackingOption::FixedLength(size_new) => {
-                        let mut data = into_fixed_integer(self.data, size_orig, size_new);
-                        let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::VariableLength(total_len) => {
-                        let mut data = into_variable_integer(self.data, size_orig, total_len);
-                        let datatype = UNSIGNED_INTEGER | VARIABLE_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::Short => {
-                        let mut data = vec![];
-                        let datatype = SHORT_UNSIGNED_INTEGER | self.data[0];
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::ShortVariable => {
-                        let mut data = into_short_variable_integer(self.data);
-                        let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::None => {
-                        let mut data = self.data;
-                        let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                }
-            }
-            DataType::SignedInteger => {
-                let len = self.shape.product();
-                if len == 0 {
-                    let mut data = vec![];
-                    let datatype = SIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    return data;
-                }
-                let size_orig = self.data.len() / len as usize;
-                match inspect_integer(&self.data, size_orig, &self.shape) {
-                    IntegerPackingOption::FixedLength(size_new) => {
-                        let mut data = into_fixed_integer(self.data, size_orig, size_new);
-                        let datatype = SIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::VariableLength(total_len) => {
-                        let mut data = into_variable_integer(self.data, size_orig, total_len);
-                        let datatype = SIGNED_INTEGER | VARIABLE_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::Short => {
-                        let mut data = vec![];
-                        let datatype = SHORT_SIGNED_INTEGER | self.data[0];
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::ShortVariable => {
-                        let mut data = into_short_variable_integer(self.data);
-                        let datatype = SIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    IntegerPackingOption::None => {
-                        let mut data = self.data;
-                        let datatype = SIGNED_INTEGER | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                }
-            }
-            DataType::Real => {
-                let len = self.shape.product();
-                if len == 0 {
-                    let mut data = vec![];
-                    let datatype = REAL | FIXED_LENGTH | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    return data;
-                }
-                let size_orig = self.data.len() / len as usize;
-                match inspect_float(&self.data, size_orig) {
-                    FloatPackingOption::FixedLength(size_new) => {
-                        let mut data = into_fixed_float(self.data, size_orig, size_new);
-                        let datatype = REAL | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    FloatPackingOption::VariableLength(total_len) => {
-                        let mut data = into_variable_float(self.data, size_orig, total_len);
-                        let datatype = REAL | VARIABLE_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    FloatPackingOption::None => {
-                        let mut data = self.data;
-                        let datatype = REAL | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                }
+    fn pack(mut self) -> Vec<u8> {
+        if let Some(validity) = self.validity.take() {
+            let len = self.len();
+            return into_nullable(self.pack(), validity, len, true);
+        }
+        if let Some(unit) = self.unit.take() {
+            if self.len() > 1
+                && let Some(delta) = into_delta(&self)
+            {
+                let delta = delta.pack();
+                let plain = self.pack();
+                return if delta.len() < plain.len() {
+                    into_unit(delta, unit, DELTA)
+                } else {
+                    into_unit(plain, unit, PLAIN)
+                };
             }
-            DataType::Complex => {
-                let len = self.shape.product();
-                if len == 0 {
-                    let mut data = vec![];
-                    let datatype = COMPLEX | FIXED_LENGTH | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    return data;
-                }
-                let size_orig = self.data.len() / len as usize / 2;
-                match inspect_float(&self.data, size_orig) {
-                    FloatPackingOption::FixedLength(size_new) => {
-                        let mut data = into_fixed_float(self.data, size_orig, size_new);
-                        let datatype = COMPLEX | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    FloatPackingOption::VariableLength(total_len) => {
-                        let mut data = into_variable_float(self.data, size_orig, total_len);
-                        let datatype = COMPLEX | VARIABLE_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                    FloatPackingOption::None => {
-                        let mut data = self.data;
-                        let datatype = COMPLEX | FIXED_LENGTH | self.shape.len() as u8;
-                        write_footer(&mut data, datatype, self.shape);
-                        data
-                    }
-                }
-            }
-            DataType::String => match inspect_string(&self.data, &self.shape) {
-                StringPackingOption::Dictionary(dic) => {
-                    let mut data = into_dictionary(self.data, dic);
-                    let datatype = STRING | DICTIONARY | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    data
-                }
-                StringPackingOption::None => {
-                    let mut data = self.data;
-                    let datatype = STRING | JOINED | self.shape.len() as u8;
-                    write_footer(&mut data, datatype, self.shape);
-                    data
-                }
-            },
+            return into_unit(self.pack(), unit, PLAIN);
+        }
+        if self.shape.len() > MAX_DIMENSION {
+            let shape = self.shape.clone();
+            self.shape = vec![shape.product()];
+            return into_large_dimension(self.pack(), shape);
         }
+        if let Some(nonzero) = inspect_sparse(&self) {
+            let sparse = into_sparse(&self, nonzero);
+            let dense = pack_dense(self);
+            return if sparse.len() < dense.len() {
+                sparse
+            } else {
+                dense
+            };
+        }
+        pack_dense(self)
     }
-    fn pack_as_it_is(self) -> Vec<u8> {
+    fn pack_as_it_is(mut self) -> Vec<u8> {
+        if let Some(validity) = self.validity.take() {
+            let len = self.len();
+            return into_nullable(self.pack_as_it_is(), validity, len, false);
+        }
+        if let Some(unit) = self.unit.take() {
+            return into_unit(self.pack_as_it_is(), unit, PLAIN);
+        }
+        if self.shape.len() > MAX_DIMENSION {
+            let shape = self.shape.clone();
+            self.shape = vec![shape.product()];
+            return into_large_dimension(self.pack_as_it_is(), shape);
+        }
         match self.datatype {
             DataType::UnsignedInteger => {
                 let mut data = self.data;
@@ -209,6 +128,189 @@ impl Pack for ArrayObject {
                 write_footer(&mut data, datatype, self.shape);
                 data
             }
+            DataType::Bytes => into_bytes(self, None),
+        }
+    }
+    fn pack_with(self, option: PackOption) -> Vec<u8> {
+        let data = if option.compress {
+            self.pack()
+        } else {
+            self.pack_as_it_is()
+        };
+        if option.checksum {
+            into_checksum(data)
+        } else {
+            data
+        }
+    }
+}
+
+/// Compresses the data element by element.
+fn pack_dense(obj: ArrayObject) -> Vec<u8> {
+    match obj.datatype {
+        DataType::UnsignedInteger => {
+            let len = obj.shape.product();
+            if len == 0 {
+                let mut data = vec![];
+                let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                return data;
+            }
+            let size_orig = obj.data.len() / len as usize;
+            match inspect_integer(&obj.data, size_orig, &obj.shape) {
+                IntegerPackingOption::FixedLength(size_new) => {
+                    let mut data = into_fixed_integer(obj.data, size_orig, size_new);
+                    let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::VariableLength(total_len) => {
+                    let mut data = into_variable_integer(obj.data, size_orig, total_len);
+                    let datatype = UNSIGNED_INTEGER | VARIABLE_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::Short => {
+                    let mut data = vec![];
+                    let datatype = SHORT_UNSIGNED_INTEGER | obj.data[0];
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::ShortVariable => {
+                    let mut data = into_short_variable_integer(obj.data);
+                    let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::None => {
+                    let mut data = obj.data;
+                    let datatype = UNSIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+            }
+        }
+        DataType::SignedInteger => {
+            let len = obj.shape.product();
+            if len == 0 {
+                let mut data = vec![];
+                let datatype = SIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                return data;
+            }
+            let size_orig = obj.data.len() / len as usize;
+            match inspect_integer(&obj.data, size_orig, &obj.shape) {
+                IntegerPackingOption::FixedLength(size_new) => {
+                    let mut data = into_fixed_integer(obj.data, size_orig, size_new);
+                    let datatype = SIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::VariableLength(total_len) => {
+                    let mut data = into_variable_integer(obj.data, size_orig, total_len);
+                    let datatype = SIGNED_INTEGER | VARIABLE_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::Short => {
+                    let mut data = vec![];
+                    let datatype = SHORT_SIGNED_INTEGER | obj.data[0];
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::ShortVariable => {
+                    let mut data = into_short_variable_integer(obj.data);
+                    let datatype = SIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                IntegerPackingOption::None => {
+                    let mut data = obj.data;
+                    let datatype = SIGNED_INTEGER | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+            }
+        }
+        DataType::Real => {
+            let len = obj.shape.product();
+            if len == 0 {
+                let mut data = vec![];
+                let datatype = REAL | FIXED_LENGTH | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                return data;
+            }
+            let size_orig = obj.data.len() / len as usize;
+            match inspect_float(&obj.data, size_orig) {
+                FloatPackingOption::FixedLength(size_new) => {
+                    let mut data = into_fixed_float(obj.data, size_orig, size_new);
+                    let datatype = REAL | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                FloatPackingOption::VariableLength(total_len) => {
+                    let mut data = into_variable_float(obj.data, size_orig, total_len);
+                    let datatype = REAL | VARIABLE_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                FloatPackingOption::None => {
+                    let mut data = obj.data;
+                    let datatype = REAL | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+            }
+        }
+        DataType::Complex => {
+            let len = obj.shape.product();
+            if len == 0 {
+                let mut data = vec![];
+                let datatype = COMPLEX | FIXED_LENGTH | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                return data;
+            }
+            let size_orig = obj.data.len() / len as usize / 2;
+            match inspect_float(&obj.data, size_orig) {
+                FloatPackingOption::FixedLength(size_new) => {
+                    let mut data = into_fixed_float(obj.data, size_orig, size_new);
+                    let datatype = COMPLEX | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                FloatPackingOption::VariableLength(total_len) => {
+                    let mut data = into_variable_float(obj.data, size_orig, total_len);
+                    let datatype = COMPLEX | VARIABLE_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+                FloatPackingOption::None => {
+                    let mut data = obj.data;
+                    let datatype = COMPLEX | FIXED_LENGTH | obj.shape.len() as u8;
+                    write_footer(&mut data, datatype, obj.shape);
+                    data
+                }
+            }
+        }
+        DataType::String => match inspect_string(&obj.data, &obj.shape) {
+            StringPackingOption::Dictionary(dic) => {
+                let mut data = into_dictionary(obj.data, dic);
+                let datatype = STRING | DICTIONARY | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                data
+            }
+            StringPackingOption::None => {
+                let mut data = obj.data;
+                let datatype = STRING | JOINED | obj.shape.len() as u8;
+                write_footer(&mut data, datatype, obj.shape);
+                data
+            }
+        },
+        DataType::Bytes => {
+            let dictionary = obj
+                .elements()
+                .and_then(|elements| inspect_bytes(&elements, obj.data.len()));
+            into_bytes(obj, dictionary)
         }
     }
 }
@@ -218,3 +320,16 @@ fn write_footer(data: &mut Vec<u8>, datatype: u8, shape: Vec<u64>) {
     footer.reverse();
     data.append(&mut footer);
 }
+
+/// Writes the footer of an extension: the extension code and a metadata block prefixed by its length.
+pub(crate) fn write_extension_footer(data: &mut Vec<u8>, code: u8, metadata: Vec<u64>) {
+    let metadata = varint_encode(metadata);
+    let mut footer = [
+        vec![EXTENSION | EXTENSION_VERSION, code],
+        varint_encode(vec![metadata.len() as u64]),
+        metadata,
+    ]
+    .concat();
+    footer.reverse();
+    data.append(&mut footer);
+}
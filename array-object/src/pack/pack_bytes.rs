@@ -0,0 +1,55 @@
+use crate::bitfield::*;
+use crate::convert::from_bytes::join_bytes;
+use crate::pack::pack::write_extension_footer;
+use crate::pack::varint::*;
+use crate::storage::*;
+use std::collections::HashMap;
+
+pub const JOINED_BYTES: u64 = 0;
+pub const DICTIONARY_BYTES: u64 = 1;
+
+/// Returns the distinct byte strings in the order of appearance if the dictionary encoding makes the data smaller.
+pub fn inspect_bytes(elements: &[&[u8]], len_orig: usize) -> Option<Vec<Vec<u8>>> {
+    if elements.len() <= 1 {
+        return None;
+    }
+    let mut dictionary: HashMap<&[u8], usize> = HashMap::new();
+    let mut keys = vec![];
+    for &x in elements {
+        if !dictionary.contains_key(x) {
+            dictionary.insert(x, keys.len());
+            keys.push(x.to_vec());
+        }
+    }
+    let len_dictionary =
+        varint_encode(vec![keys.len() as u64]).len() + join_bytes(&keys).len() + elements.len();
+    if keys.len() < 256 && len_dictionary < len_orig {
+        Some(keys)
+    } else {
+        None
+    }
+}
+
+/// Packs bytes data, replacing each element with an index into the dictionary if given.
+pub fn into_bytes(obj: ArrayObject, dictionary: Option<Vec<Vec<u8>>>) -> Vec<u8> {
+    let (encoding, mut data) = match dictionary {
+        Some(keys) => {
+            let elements = obj.elements().unwrap_or_default();
+            let indices: Vec<u8> = elements
+                .iter()
+                .map(|&x| keys.iter().position(|k| k == x).unwrap() as u8)
+                .collect();
+            let data = [
+                varint_encode(vec![keys.len() as u64]),
+                join_bytes(&keys),
+                indices,
+            ]
+            .concat();
+            (DICTIONARY_BYTES, data)
+        }
+        None => (JOINED_BYTES, obj.data),
+    };
+    let metadata = [vec![encoding], obj.shape].concat();
+    write_extension_footer(&mut data, BYTES, metadata);
+    data
+}
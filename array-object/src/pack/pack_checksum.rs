@@ -0,0 +1,39 @@
+use crate::bitfield::*;
+use crate::pack::pack::write_extension_footer;
+
+pub const CRC32C: u64 = 0;
+
+/// The lookup table of CRC-32C (Castagnoli) in the reflected form.
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32C of the data.
+pub fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &b| {
+        CRC32C_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Appends the checksum of the packed data.
+pub fn into_checksum(mut data: Vec<u8>) -> Vec<u8> {
+    let checksum = crc32c(&data) as u64;
+    write_extension_footer(&mut data, CHECKSUM, vec![CRC32C, checksum]);
+    data
+}
@@ -0,0 +1,12 @@
+use crate::bitfield::*;
+use crate::pack::pack::write_extension_footer;
+
+/// The largest dimension that fits in the footer of the data type.
+pub const MAX_DIMENSION: usize = DIMENSION_MASK as usize;
+
+/// Appends the shape of an array of more than 15 dimensions to the data packed as a one-dimensional array.
+pub fn into_large_dimension(mut data: Vec<u8>, shape: Vec<u64>) -> Vec<u8> {
+    let metadata = [vec![shape.len() as u64], shape].concat();
+    write_extension_footer(&mut data, DIMENSION, metadata);
+    data
+}
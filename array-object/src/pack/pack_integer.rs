@@ -1,5 +1,6 @@
 use crate::misc::Product;
 use crate::bitfield::TYPE_MASK;
+use crate::pack::varint::varint_encode;
 
 #[derive(Debug)]
 pub enum IntegerPackingOption {
@@ -23,9 +24,11 @@ pub fn inspect_integer(data: &Vec<u8>, size_orig: usize, shape: &Vec<u64>) -> In
     }
     let mut count: Vec<u64> = vec![0; 5];
     let mut additional: u64 = 0;
+    let mut max_size = 1;
     for x in data.chunks(size_orig) {
         let pos = x.iter().rev().position(|i| *i > 0).unwrap_or(size_orig - 1);
         let min_size = size_orig - pos;
+        max_size = max_size.max(min_size);
         match min_size {
             1 => {
                 count[0] += 1;
@@ -44,14 +47,18 @@ pub fn inspect_integer(data: &Vec<u8>, size_orig: usize, shape: &Vec<u64>) -> In
             }
             _ => {
                 count[4] += 1;
-                additional += min_size as u64 + 1;
+                additional += (min_size + extended_length(min_size).len()) as u64;
             }
         }
     }
     let len_orig = data.len() as u64;
     let n_elem = len_orig / size_orig as u64;
     let pos = count.iter().rev().position(|i| *i > 0).unwrap_or(4);
-    let size_fixed = 2u64.pow(4 - pos as u32);
+    let size_fixed = if max_size > 16 {
+        max_size.next_power_of_two() as u64
+    } else {
+        2u64.pow(4 - pos as u32)
+    };
     let len_fixed = size_fixed * n_elem;
     let len_variable =
         count[0] + count[1] * 2 + count[2] * 4 + count[3] * 8 + additional + (n_elem - 1) / 4 + 1;
@@ -121,7 +128,7 @@ pub fn into_variable_integer(data_orig: Vec<u8>, size_orig: usize, total_len: u6
                     for i in 0..7 {
                         temp.push(x[i]);
                     }
-                    temp.push(min_size as u8 | 0b1000_0000u8);
+                    temp.append(&mut extended_length(min_size));
                     for i in 7..min_size {
                         temp.push(x[i]);
                     }
@@ -141,6 +148,15 @@ pub fn into_variable_integer(data_orig: Vec<u8>, size_orig: usize, total_len: u6
     data
 }
 
+/// The length byte of an integer longer than 8 bytes. Lengths above 127 bytes are marked by zero and followed by the length in a variable integer.
+fn extended_length(min_size: usize) -> Vec<u8> {
+    if min_size < 128 {
+        vec![min_size as u8 | 0b1000_0000u8]
+    } else {
+        [vec![0b1000_0000u8], varint_encode(vec![min_size as u64])].concat()
+    }
+}
+
 pub fn into_short_variable_integer(mut data_orig: Vec<u8>) -> Vec<u8> {
     while data_orig.len() > 0 && *data_orig.last().unwrap() == 0 {
         data_orig.pop().unwrap();
@@ -0,0 +1,35 @@
+use crate::bitfield::*;
+use crate::pack::pack::write_extension_footer;
+use crate::pack::varint::*;
+
+pub const BITMAP: u64 = 0;
+pub const RUN_LENGTH: u64 = 1;
+
+/// Appends the validity mask to the packed data, with the run-length encoding if it is smaller than the bitmap.
+pub fn into_nullable(mut data: Vec<u8>, validity: Vec<u8>, len: usize, compress: bool) -> Vec<u8> {
+    let packed_len = data.len() as u64;
+    let runs = varint_encode(run_lengths(&validity, len));
+    let (encoding, mut mask) = if compress && runs.len() < validity.len() {
+        (RUN_LENGTH, runs)
+    } else {
+        (BITMAP, validity)
+    };
+    data.append(&mut mask);
+    write_extension_footer(&mut data, NULLABLE, vec![encoding, packed_len]);
+    data
+}
+
+/// Lengths of the alternating runs of valid and null elements, starting with valid ones.
+fn run_lengths(bitmap: &[u8], len: usize) -> Vec<u64> {
+    let mut runs = vec![0u64];
+    let mut current = true;
+    for i in 0..len {
+        let valid = bitmap[i / 8] & (1 << (i % 8)) != 0;
+        if valid != current {
+            runs.push(0);
+            current = valid;
+        }
+        *runs.last_mut().unwrap() += 1;
+    }
+    runs
+}
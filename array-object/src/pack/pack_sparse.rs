@@ -0,0 +1,105 @@
+use crate::bitfield::*;
+use crate::misc::Product;
+use crate::pack::pack::{Pack, write_extension_footer};
+use crate::storage::*;
+
+pub const COO: u64 = 0;
+pub const CSR: u64 = 1;
+
+/// Returns the positions of the non-zero elements if more than half of the elements are zero.
+pub fn inspect_sparse(obj: &ArrayObject) -> Option<Vec<usize>> {
+    if matches!(obj.datatype, DataType::String | DataType::Bytes) || obj.shape.is_empty() {
+        return None;
+    }
+    let len = obj.shape.product() as usize;
+    if len == 0 {
+        return None;
+    }
+    let size = obj.data.len() / len;
+    let nonzero: Vec<usize> = obj
+        .data
+        .chunks(size)
+        .enumerate()
+        .filter(|(_, x)| x.iter().any(|b| *b != 0))
+        .map(|(i, _)| i)
+        .collect();
+    if nonzero.len() * 2 < len {
+        Some(nonzero)
+    } else {
+        None
+    }
+}
+
+/// Encodes the non-zero elements and their indices, each as a packed 1-dimensional object.
+pub fn into_sparse(obj: &ArrayObject, nonzero: Vec<usize>) -> Vec<u8> {
+    let size = obj.data.len() / obj.shape.product() as usize;
+    let values = ArrayObject {
+        data: nonzero
+            .iter()
+            .flat_map(|&i| obj.data[i * size..(i + 1) * size].to_vec())
+            .collect(),
+        shape: vec![nonzero.len() as u64],
+        datatype: obj.datatype.clone(),
+        validity: None,
+        unit: None,
+    };
+    let (layout, indices) = if obj.shape.len() == 2 && obj.shape[0] < nonzero.len() as u64 {
+        (CSR, into_csr(&obj.shape, &nonzero))
+    } else {
+        (COO, into_coo(&obj.shape, &nonzero))
+    };
+    write_sparse(values, layout, indices, &obj.shape)
+}
+
+/// Packs the values and the indices in the layout with the footer of the sparse extension.
+pub fn write_sparse(values: ArrayObject, layout: u64, indices: Vec<u64>, shape: &[u64]) -> Vec<u8> {
+    let indices = ArrayObject {
+        shape: vec![indices.len() as u64],
+        data: indices.into_iter().flat_map(|x| x.to_le_bytes()).collect(),
+        datatype: DataType::UnsignedInteger,
+        validity: None,
+        unit: None,
+    };
+    let mut data = values.pack();
+    let values_len = data.len() as u64;
+    data.append(&mut indices.pack());
+    let metadata = [vec![layout, values_len], shape.to_vec()].concat();
+    write_extension_footer(&mut data, SPARSE, metadata);
+    data
+}
+
+pub fn indices_len(shape: &[u64], layout: u64, nnz: usize) -> u64 {
+    match layout {
+        CSR => shape[0].saturating_add(1 + nnz as u64),
+        _ => (shape.len() as u64).saturating_mul(nnz as u64),
+    }
+}
+
+/// Coordinates of each element in row major order.
+fn into_coo(shape: &[u64], nonzero: &[usize]) -> Vec<u64> {
+    let mut res = Vec::with_capacity(shape.len() * nonzero.len());
+    for &i in nonzero {
+        let mut index = i as u64;
+        let start = res.len();
+        for &n in shape.iter().rev() {
+            res.push(index % n);
+            index /= n;
+        }
+        res[start..].reverse();
+    }
+    res
+}
+
+/// Row pointers followed by column indices.
+fn into_csr(shape: &[u64], nonzero: &[usize]) -> Vec<u64> {
+    let ncols = shape[1] as usize;
+    let mut indptr = vec![0u64; shape[0] as usize + 1];
+    for &i in nonzero {
+        indptr[i / ncols + 1] += 1;
+    }
+    for r in 0..shape[0] as usize {
+        indptr[r + 1] += indptr[r];
+    }
+    indptr.extend(nonzero.iter().map(|&i| (i % ncols) as u64));
+    indptr
+}
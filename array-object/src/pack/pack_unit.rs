@@ -0,0 +1,58 @@
+use crate::bitfield::*;
+use crate::convert::cast::{is_wide, read_signed, write_signed};
+use crate::convert::zigzag::Zigzag;
+use crate::pack::pack::write_extension_footer;
+use crate::storage::*;
+use crate::unit::*;
+
+pub const PLAIN: u64 = 0;
+pub const DELTA: u64 = 1;
+
+pub const TIMESTAMP: u64 = 0;
+pub const DATE: u64 = 1;
+pub const DURATION: u64 = 2;
+pub const DECIMAL: u64 = 3;
+
+/// Returns the codes of the kind and the resolution of the unit. The resolution of decimal numbers is the exponent in the zigzag encoding.
+pub fn unit_codes(unit: Unit) -> [u64; 2] {
+    let code = |x: TimeUnit| match x {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    };
+    match unit {
+        Unit::Timestamp(x) => [TIMESTAMP, code(x)],
+        Unit::Date => [DATE, 0],
+        Unit::Duration(x) => [DURATION, code(x)],
+        Unit::Decimal(x) => [DECIMAL, x.zigzag() as u32 as u64],
+    }
+}
+
+/// Replaces each element with the difference from the previous one in row major order. Returns None on overflow or for integers longer than 128 bits.
+pub fn into_delta(obj: &ArrayObject) -> Option<ArrayObject> {
+    if is_wide(obj) {
+        return None;
+    }
+    let val = read_signed(obj);
+    let mut prev = 0i128;
+    let mut delta = Vec::with_capacity(val.len());
+    for x in val {
+        delta.push(x.checked_sub(prev)?);
+        prev = x;
+    }
+    Some(ArrayObject {
+        data: write_signed(delta),
+        shape: obj.shape.clone(),
+        datatype: DataType::SignedInteger,
+        validity: None,
+        unit: None,
+    })
+}
+
+/// Appends the unit to the packed data.
+pub fn into_unit(mut data: Vec<u8>, unit: Unit, encoding: u64) -> Vec<u8> {
+    let [kind, resolution] = unit_codes(unit);
+    write_extension_footer(&mut data, UNIT, vec![kind, resolution, encoding]);
+    data
+}
@@ -1,113 +1,184 @@
 use crate::bitfield::*;
 use crate::error::ArrayObjectError;
 use crate::misc::Product;
+use crate::pack::unpack_bytes::*;
+use crate::pack::unpack_checksum::*;
+use crate::pack::unpack_dimension::*;
 use crate::pack::unpack_float::*;
 use crate::pack::unpack_integer::*;
+use crate::pack::unpack_nullable::*;
+use crate::pack::unpack_sparse::*;
 use crate::pack::unpack_string::*;
+use crate::pack::unpack_unit::*;
 use crate::pack::varint::*;
 use crate::storage::*;
 
+/// Options for `Unpack::unpack_with()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum UnpackOption {
+    /// The checksum is verified if the binary has one.
+    #[default]
+    Verify,
+    /// The checksum is not verified, e.g. when the storage already guarantees the integrity.
+    SkipVerification,
+}
+
 /// Restore from binary.
 pub trait Unpack {
     /// Restore ArrayObject from a binary data.
     fn unpack(data: Vec<u8>) -> Result<Self, ArrayObjectError>
     where
         Self: Sized;
+    /// Restore ArrayObject from a binary data with the options.
+    fn unpack_with(data: Vec<u8>, option: UnpackOption) -> Result<Self, ArrayObjectError>
+    where
+        Self: Sized;
 }
 
 impl Unpack for ArrayObject {
-    fn unpack(mut data: Vec<u8>) -> Result<Self, ArrayObjectError> {
-        let (datatype, format, shape, shortdata) = read_footer(&mut data);
-        match datatype & TYPE_MASK {
-            SHORT_UNSIGNED_INTEGER => {
-                if !data.is_empty() {
-                    return Err(ArrayObjectError::UnableToDecode);
-                }
-                Ok(Self {
-                    data: shortdata.unwrap(),
-                    shape: vec![],
-                    datatype: DataType::UnsignedInteger,
-                })
+    fn unpack(data: Vec<u8>) -> Result<Self, ArrayObjectError> {
+        Self::unpack_with(data, UnpackOption::Verify)
+    }
+    fn unpack_with(data: Vec<u8>, option: UnpackOption) -> Result<Self, ArrayObjectError> {
+        unpack_nested(data, option, &[])
+    }
+}
+
+/// Restores the array, which is the payload of the extensions in `seen` if any.
+/// Each extension may appear only once in the nesting, so that a crafted binary cannot nest them without bound.
+pub(crate) fn unpack_nested(
+    mut data: Vec<u8>,
+    option: UnpackOption,
+    seen: &[u8],
+) -> Result<ArrayObject, ArrayObjectError> {
+    if data.is_empty() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    if data.last().is_some_and(|x| x & TYPE_MASK == EXTENSION) {
+        let (code, metadata) = read_extension_footer(&mut data)?;
+        if seen.contains(&code) {
+            return Err(ArrayObjectError::UnableToDecode);
+        }
+        let seen = &[seen, &[code]].concat();
+        return match code {
+            CHECKSUM => {
+                verify_checksum(&data, metadata, option == UnpackOption::Verify)?;
+                unpack_nested(data, option, seen)
             }
-            SHORT_SIGNED_INTEGER => {
-                if !data.is_empty() {
-                    return Err(ArrayObjectError::UnableToDecode);
-                }
-                Ok(Self {
-                    data: shortdata.unwrap(),
-                    shape: vec![],
-                    datatype: DataType::SignedInteger,
-                })
+            SPARSE => from_sparse(data, metadata, option, seen),
+            NULLABLE => from_nullable(data, metadata, option, seen),
+            BYTES => from_bytes(data, metadata),
+            UNIT => from_unit(data, metadata, option, seen),
+            DIMENSION => from_large_dimension(data, metadata, option, seen),
+            code => Err(ArrayObjectError::UnsupportedExtension(code)),
+        };
+    }
+    let (datatype, format, shape, shortdata) = read_footer(&mut data);
+    match datatype & TYPE_MASK {
+        SHORT_UNSIGNED_INTEGER => {
+            if !data.is_empty() {
+                return Err(ArrayObjectError::UnableToDecode);
             }
-            UNSIGNED_INTEGER => {
-                let shape = shape.unwrap();
-                if format == VARIABLE_LENGTH {
-                    data = from_variable_integer(data);
-                } else {
-                    let total_len = shape.product();
-                    while (data.len() == 0 && total_len > 0)
-                        || (total_len == 1 && 2usize.pow(data.len().ilog2()) != data.len())
-                    {
-                        data.push(0);
-                    }
-                }
-                Ok(Self {
-                    data,
-                    shape,
-                    datatype: DataType::UnsignedInteger,
-                })
+            Ok(ArrayObject {
+                data: shortdata.unwrap(),
+                shape: vec![],
+                datatype: DataType::UnsignedInteger,
+                validity: None,
+                unit: None,
+            })
+        }
+        SHORT_SIGNED_INTEGER => {
+            if !data.is_empty() {
+                return Err(ArrayObjectError::UnableToDecode);
             }
-            SIGNED_INTEGER => {
-                let shape = shape.unwrap();
-                if format == VARIABLE_LENGTH {
-                    data = from_variable_integer(data);
-                } else {
-                    let total_len = shape.product();
-                    while (data.len() == 0 && total_len > 0)
-                        || (total_len == 1 && 2usize.pow(data.len().ilog2()) != data.len())
-                    {
-                        data.push(0);
-                    }
+            Ok(ArrayObject {
+                data: shortdata.unwrap(),
+                shape: vec![],
+                datatype: DataType::SignedInteger,
+                validity: None,
+                unit: None,
+            })
+        }
+        UNSIGNED_INTEGER => {
+            let shape = shape.unwrap();
+            if format == VARIABLE_LENGTH {
+                data = from_variable_integer(data);
+            } else {
+                let total_len = shape.product();
+                while (data.len() == 0 && total_len > 0)
+                    || (total_len == 1 && 2usize.pow(data.len().ilog2()) != data.len())
+                {
+                    data.push(0);
                 }
-                Ok(Self {
-                    data,
-                    shape,
-                    datatype: DataType::SignedInteger,
-                })
             }
-            REAL => {
-                if format == VARIABLE_LENGTH {
-                    data = from_variable_float(data);
+            Ok(ArrayObject {
+                data,
+                shape,
+                datatype: DataType::UnsignedInteger,
+                validity: None,
+                unit: None,
+            })
+        }
+        SIGNED_INTEGER => {
+            let shape = shape.unwrap();
+            if format == VARIABLE_LENGTH {
+                data = from_variable_integer(data);
+            } else {
+                let total_len = shape.product();
+                while (data.len() == 0 && total_len > 0)
+                    || (total_len == 1 && 2usize.pow(data.len().ilog2()) != data.len())
+                {
+                    data.push(0);
                 }
-                Ok(Self {
-                    data,
-                    shape: shape.unwrap(),
-                    datatype: DataType::Real,
-                })
             }
-            COMPLEX => {
-                if format == VARIABLE_LENGTH {
-                    data = from_variable_float(data);
-                }
-                Ok(Self {
-                    data,
-                    shape: shape.unwrap(),
-                    datatype: DataType::Complex,
-                })
+            Ok(ArrayObject {
+                data,
+                shape,
+                datatype: DataType::SignedInteger,
+                validity: None,
+                unit: None,
+            })
+        }
+        REAL => {
+            if format == VARIABLE_LENGTH {
+                data = from_variable_float(data);
             }
-            STRING => {
-                if format == DICTIONARY {
-                    data = from_dictionary(data);
-                }
-                Ok(Self {
-                    data,
-                    shape: shape.unwrap(),
-                    datatype: DataType::String,
-                })
+            Ok(ArrayObject {
+                data,
+                shape: shape.unwrap(),
+                datatype: DataType::Real,
+                validity: None,
+                unit: None,
+            })
+        }
+        COMPLEX => {
+            if format == VARIABLE_LENGTH {
+                data = from_variable_float(data);
             }
-            _ => {
-                return Err(ArrayObjectError::UnableToDecode);
+            Ok(ArrayObject {
+                data,
+                shape: shape.unwrap(),
+                datatype: DataType::Complex,
+                validity: None,
+                unit: None,
+            })
+        }
+        STRING => {
+            if format == DICTIONARY {
+                data = from_dictionary(data)?;
             }
+            let shape = shape.unwrap();
+            validate_string(&data, shape.product())?;
+            Ok(ArrayObject {
+                data,
+                shape,
+                datatype: DataType::String,
+                validity: None,
+                unit: None,
+            })
+        }
+        _ => {
+            return Err(ArrayObjectError::UnableToDecode);
         }
     }
 }
@@ -127,3 +198,21 @@ fn read_footer(bytes: &mut Vec<u8>) -> (u8, u8, Option<Vec<u64>>, Option<Vec<u8>
         (ty, format, Some(shape), None)
     }
 }
+
+/// Reads the footer of an extension and returns the extension code and the metadata.
+fn read_extension_footer(bytes: &mut Vec<u8>) -> Result<(u8, Vec<u64>), ArrayObjectError> {
+    let version = bytes.pop().ok_or(ArrayObjectError::UnableToDecode)? & VERSION_MASK;
+    if version > EXTENSION_VERSION {
+        return Err(ArrayObjectError::UnsupportedVersion(version));
+    }
+    let code = bytes.pop().ok_or(ArrayObjectError::UnableToDecode)?;
+    let (len, n) = varint_decode(bytes.iter().rev(), 1);
+    if len.len() != 1 || len[0] as usize > bytes.len() - n {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    bytes.truncate(bytes.len() - n);
+    let start = bytes.len() - len[0] as usize;
+    let metadata: Vec<u8> = bytes.split_off(start).into_iter().rev().collect();
+    let (metadata, _) = varint_decode(metadata.iter(), usize::MAX);
+    Ok((code, metadata))
+}
@@ -0,0 +1,47 @@
+use crate::convert::from_bytes::join_bytes;
+use crate::convert::into_bytes::split_bytes;
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::pack::pack_bytes::*;
+use crate::pack::varint::*;
+use crate::storage::*;
+
+/// Restores bytes data from the payload and the metadata of the extension.
+pub fn from_bytes(data: Vec<u8>, metadata: Vec<u64>) -> Result<ArrayObject, ArrayObjectError> {
+    let Some((&encoding, shape)) = metadata.split_first() else {
+        return Err(ArrayObjectError::UnableToDecode);
+    };
+    let shape = shape.to_vec();
+    let len = shape.product() as usize;
+    let data = match encoding {
+        JOINED_BYTES => data,
+        DICTIONARY_BYTES => {
+            let (n_keys, k) = varint_decode(data.iter(), 1);
+            let n_keys = *n_keys.first().ok_or(ArrayObjectError::UnableToDecode)? as usize;
+            let split = data
+                .len()
+                .checked_sub(len)
+                .filter(|&i| i >= k)
+                .ok_or(ArrayObjectError::UnableToDecode)?;
+            let keys =
+                split_bytes(&data[k..split], n_keys).ok_or(ArrayObjectError::UnableToDecode)?;
+            let elements = data[split..]
+                .iter()
+                .map(|&i| keys.get(i as usize).copied())
+                .collect::<Option<Vec<_>>>()
+                .ok_or(ArrayObjectError::UnableToDecode)?;
+            join_bytes(elements)
+        }
+        _ => return Err(ArrayObjectError::UnableToDecode),
+    };
+    if split_bytes(&data, len).is_none() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    Ok(ArrayObject {
+        data,
+        shape,
+        datatype: DataType::Bytes,
+        validity: None,
+        unit: None,
+    })
+}
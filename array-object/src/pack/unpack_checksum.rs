@@ -0,0 +1,21 @@
+use crate::error::ArrayObjectError;
+use crate::pack::pack_checksum::*;
+
+/// Checks the metadata of the checksum and compares it with the data if `verify` is true.
+pub fn verify_checksum(
+    data: &[u8],
+    metadata: Vec<u64>,
+    verify: bool,
+) -> Result<(), ArrayObjectError> {
+    if metadata.len() != 2 || metadata[0] != CRC32C || metadata[1] > u32::MAX as u64 {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let expected = metadata[1] as u32;
+    if verify {
+        let found = crc32c(data);
+        if found != expected {
+            return Err(ArrayObjectError::ChecksumMismatch(expected, found));
+        }
+    }
+    Ok(())
+}
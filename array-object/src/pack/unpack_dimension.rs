@@ -0,0 +1,24 @@
+use crate::error::ArrayObjectError;
+use crate::misc::Product;
+use crate::pack::pack_dimension::*;
+use crate::pack::unpack::{UnpackOption, unpack_nested};
+use crate::storage::*;
+
+/// Restores the one-dimensional array and reshapes it into the original shape.
+pub fn from_large_dimension(
+    data: Vec<u8>,
+    metadata: Vec<u64>,
+    option: UnpackOption,
+    seen: &[u8],
+) -> Result<ArrayObject, ArrayObjectError> {
+    if metadata.is_empty() || metadata[0] as usize != metadata.len() - 1 {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let shape = metadata[1..].to_vec();
+    let mut obj = unpack_nested(data, option, seen)?;
+    if shape.len() <= MAX_DIMENSION || obj.shape.len() != 1 || obj.shape[0] != shape.product() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    obj.shape = shape;
+    Ok(obj)
+}
@@ -1,3 +1,5 @@
+use crate::pack::varint::varint_decode;
+
 pub fn from_variable_integer(packed: Vec<u8>) -> Vec<u8> {
     let mut data = vec![];
     let mut pos_header = 0;
@@ -38,14 +40,20 @@ pub fn from_variable_integer(packed: Vec<u8>) -> Vec<u8> {
                         }
                         pos += 8;
                     } else {
-                        let len = (packed[pos + 7] & 0b0111_1111) as usize;
+                        let mut len = (packed[pos + 7] & 0b0111_1111) as usize;
+                        let mut offset = 0;
+                        if len == 0 {
+                            let (n, k) = varint_decode(packed[pos + 8..].iter(), 1);
+                            len = n[0] as usize;
+                            offset = k;
+                        }
                         for i in 0..7 {
                             temp.push(packed[pos + i]);
                         }
                         for i in 8..len + 1 {
-                            temp.push(packed[pos + i]);
+                            temp.push(packed[pos + offset + i]);
                         }
-                        pos += len + 1;
+                        pos += len + 1 + offset;
                     }
                     data.push(temp);
                 }
@@ -60,16 +68,7 @@ pub fn from_variable_integer(packed: Vec<u8>) -> Vec<u8> {
         pos_header = pos;
     }
     if let Some(max_bytes) = data.iter().map(|x| x.len()).max() {
-        let size = match max_bytes {
-            1 => 1,
-            2 => 2,
-            4 => 4,
-            8 => 8,
-            9..=16 => 16,
-            _ => {
-                panic!();
-            }
-        };
+        let size = max_bytes.next_power_of_two();
         for x in &mut data {
             for _ in 0..(size - x.len()) {
                 x.push(0);
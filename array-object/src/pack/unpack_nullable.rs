@@ -0,0 +1,41 @@
+use crate::error::ArrayObjectError;
+use crate::nullable::into_bitmap;
+use crate::pack::pack_nullable::*;
+use crate::pack::unpack::{UnpackOption, unpack_nested};
+use crate::pack::varint::*;
+use crate::storage::*;
+
+/// Restores the array and attaches the validity mask.
+pub fn from_nullable(
+    mut data: Vec<u8>,
+    metadata: Vec<u64>,
+    option: UnpackOption,
+    seen: &[u8],
+) -> Result<ArrayObject, ArrayObjectError> {
+    if metadata.len() != 2 || metadata[1] as usize > data.len() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let mask = data.split_off(metadata[1] as usize);
+    let mut obj = unpack_nested(data, option, seen)?;
+    if obj.validity.is_some() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let len = obj.len();
+    let validity = match metadata[0] {
+        BITMAP if mask.len() == len.div_ceil(8) => mask,
+        RUN_LENGTH => {
+            let (runs, _) = varint_decode(mask.iter(), usize::MAX);
+            if runs.iter().sum::<u64>() != len as u64 {
+                return Err(ArrayObjectError::UnableToDecode);
+            }
+            let mut validity = Vec::with_capacity(len);
+            for (i, n) in runs.into_iter().enumerate() {
+                validity.extend(std::iter::repeat_n(i % 2 == 0, n as usize));
+            }
+            into_bitmap(&validity)
+        }
+        _ => return Err(ArrayObjectError::UnableToDecode),
+    };
+    obj.validity = Some(validity);
+    Ok(obj)
+}
@@ -0,0 +1,117 @@
+use crate::convert::cast::read_unsigned;
+use crate::error::ArrayObjectError;
+use crate::pack::pack_sparse::*;
+use crate::pack::unpack::{UnpackOption, unpack_nested};
+use crate::storage::*;
+
+/// Restores the dense array from the non-zero elements and their indices.
+pub fn from_sparse(
+    mut data: Vec<u8>,
+    metadata: Vec<u64>,
+    option: UnpackOption,
+    seen: &[u8],
+) -> Result<ArrayObject, ArrayObjectError> {
+    if metadata.len() < 3 || metadata.len() > 17 || metadata[1] as usize > data.len() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let layout = metadata[0];
+    let shape = metadata[2..].to_vec();
+    let indices = data.split_off(metadata[1] as usize);
+    let values = unpack_nested(data, option, seen)?;
+    let indices = unpack_nested(indices, option, seen)?;
+    if values.shape.len() != 1
+        || matches!(values.datatype, DataType::String | DataType::Bytes)
+        || indices.datatype != DataType::UnsignedInteger
+    {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let nnz = values.len();
+    if shape
+        .iter()
+        .try_fold(1u64, |a, &b| a.checked_mul(b))
+        .is_none_or(|x| x < nnz as u64)
+    {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let indices = read_unsigned(&indices);
+    if indices.len() as u64 != indices_len(&shape, layout, nnz) {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let positions = match layout {
+        COO => from_coo(&shape, &indices),
+        CSR => from_csr(&shape, &indices),
+        _ => None,
+    }
+    .ok_or(ArrayObjectError::UnableToDecode)?;
+    let size = match (nnz, &values.datatype) {
+        (0, DataType::Real) => 4,
+        (0, DataType::Complex) => 8,
+        (0, _) => 1,
+        _ => values.data.len() / nnz,
+    };
+    let len = shape
+        .iter()
+        .try_fold(size as u64, |a, &b| a.checked_mul(b))
+        .and_then(|x| usize::try_from(x).ok())
+        .ok_or(ArrayObjectError::UnableToDecode)?;
+    // A huge shape with few elements is an error rather than an abort on the allocation.
+    Vec::<u8>::new()
+        .try_reserve_exact(len)
+        .or(Err(ArrayObjectError::UnableToDecode))?;
+    let mut dense = vec![0u8; len];
+    for (k, p) in positions.into_iter().enumerate() {
+        dense[p * size..(p + 1) * size].copy_from_slice(&values.data[k * size..(k + 1) * size]);
+    }
+    Ok(ArrayObject {
+        data: dense,
+        shape,
+        datatype: values.datatype,
+        validity: None,
+        unit: None,
+    })
+}
+
+fn from_coo(shape: &[u64], indices: &[u128]) -> Option<Vec<usize>> {
+    if shape.is_empty() {
+        return None;
+    }
+    indices
+        .chunks(shape.len())
+        .map(|coord| {
+            let mut index = 0;
+            for (&x, &n) in coord.iter().zip(shape) {
+                if x >= n as u128 {
+                    return None;
+                }
+                index = index * n as usize + x as usize;
+            }
+            Some(index)
+        })
+        .collect()
+}
+
+fn from_csr(shape: &[u64], indices: &[u128]) -> Option<Vec<usize>> {
+    if shape.len() != 2 {
+        return None;
+    }
+    let (nrows, ncols) = (shape[0] as usize, shape[1] as usize);
+    let (indptr, cols) = indices.split_at(nrows + 1);
+    let mut res = Vec::with_capacity(cols.len());
+    for r in 0..nrows {
+        let (start, end) = (indptr[r] as usize, indptr[r + 1] as usize);
+        if start > end || end > cols.len() {
+            return None;
+        }
+        for &c in &cols[start..end] {
+            if c >= ncols as u128 {
+                return None;
+            }
+            res.push(r * ncols + c as usize);
+        }
+    }
+    if res.len() == cols.len() {
+        Some(res)
+    } else {
+        None
+    }
+}
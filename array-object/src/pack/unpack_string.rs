@@ -1,5 +1,7 @@
-pub fn from_dictionary(packed: Vec<u8>) -> Vec<u8> {
-    let dic_len = packed[0];
+use crate::error::ArrayObjectError;
+
+pub fn from_dictionary(packed: Vec<u8>) -> Result<Vec<u8>, ArrayObjectError> {
+    let dic_len = *packed.first().ok_or(ArrayObjectError::UnableToDecode)?;
     let mut prev_pos = 1;
     let mut dictionary = vec![];
     for _ in 0..dic_len {
@@ -7,15 +9,32 @@ pub fn from_dictionary(packed: Vec<u8>) -> Vec<u8> {
             .iter()
             .skip(prev_pos)
             .position(|&x| x == 255)
-            .unwrap();
+            .ok_or(ArrayObjectError::UnableToDecode)?;
         dictionary.push(packed[prev_pos..prev_pos + p].to_vec());
         prev_pos += p + 1;
     }
     let mut data = vec![];
     for key in packed.iter().skip(prev_pos) {
-        data.push(dictionary[*key as usize].clone());
+        let key = dictionary
+            .get(*key as usize)
+            .ok_or(ArrayObjectError::UnableToDecode)?;
+        data.push(key.clone());
         data.push(vec![255]);
     }
     data.pop();
-    data.concat()
+    Ok(data.concat())
+}
+
+/// Checks that each element of the joined strings is valid UTF-8.
+pub fn validate_string(data: &[u8], len: u64) -> Result<(), ArrayObjectError> {
+    let mut count = 0;
+    for x in data.split(|&x| x == 255) {
+        std::str::from_utf8(x).map_err(|_| ArrayObjectError::UnableToDecode)?;
+        count += 1;
+    }
+    if len == count || (len == 0 && data.is_empty()) {
+        Ok(())
+    } else {
+        Err(ArrayObjectError::UnableToDecode)
+    }
 }
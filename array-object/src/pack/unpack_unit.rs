@@ -0,0 +1,55 @@
+use crate::convert::cast::{read_signed, write_signed};
+use crate::convert::zigzag::Zigzag;
+use crate::error::ArrayObjectError;
+use crate::pack::pack_unit::*;
+use crate::pack::unpack::{UnpackOption, unpack_nested};
+use crate::storage::*;
+use crate::unit::*;
+
+/// Restores the array of signed integers and attaches the unit.
+pub fn from_unit(
+    data: Vec<u8>,
+    metadata: Vec<u64>,
+    option: UnpackOption,
+    seen: &[u8],
+) -> Result<ArrayObject, ArrayObjectError> {
+    if metadata.len() != 3 {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    let resolution = match metadata[1] {
+        0 => Some(TimeUnit::Second),
+        1 => Some(TimeUnit::Millisecond),
+        2 => Some(TimeUnit::Microsecond),
+        3 => Some(TimeUnit::Nanosecond),
+        _ => None,
+    };
+    let unit = match (metadata[0], resolution) {
+        (TIMESTAMP, Some(resolution)) => Unit::Timestamp(resolution),
+        (DATE, _) => Unit::Date,
+        (DURATION, Some(resolution)) => Unit::Duration(resolution),
+        (DECIMAL, _) => Unit::Decimal(
+            (u32::try_from(metadata[1]).map_err(|_| ArrayObjectError::UnableToDecode)? as i32)
+                .straight(),
+        ),
+        _ => return Err(ArrayObjectError::UnableToDecode),
+    };
+    let mut obj = unpack_nested(data, option, seen)?;
+    if obj.datatype != DataType::SignedInteger || obj.validity.is_some() || obj.unit.is_some() {
+        return Err(ArrayObjectError::UnableToDecode);
+    }
+    match metadata[2] {
+        PLAIN => {}
+        DELTA => {
+            let mut sum = 0i128;
+            let mut val = vec![];
+            for x in read_signed(&obj) {
+                sum = sum.checked_add(x).ok_or(ArrayObjectError::UnableToDecode)?;
+                val.push(sum);
+            }
+            obj.data = write_signed(val);
+        }
+        _ => return Err(ArrayObjectError::UnableToDecode),
+    }
+    obj.unit = Some(unit);
+    Ok(obj)
+}
@@ -1,4 +1,5 @@
 use crate::misc::Product;
+use crate::unit::Unit;
 
 /// The type of the elements.
 #[derive(Debug, Clone, PartialEq)]
@@ -8,6 +9,7 @@ pub enum DataType {
     Real,
     Complex,
     String,
+    Bytes,
 }
 
 /// The main array storage with type abstraction.
@@ -18,6 +20,8 @@ pub struct ArrayObject {
     pub(crate) data: Vec<u8>,
     pub(crate) shape: Vec<u64>,
     pub(crate) datatype: DataType,
+    pub(crate) validity: Option<Vec<u8>>,
+    pub(crate) unit: Option<Unit>,
 }
 
 impl ArrayObject {
@@ -28,7 +32,7 @@ impl ArrayObject {
                 Some(8 * self.data.len() / self.shape.product() as usize)
             }
             DataType::Complex => Some(8 * self.data.len() / self.shape.product() as usize / 2),
-            DataType::String => None,
+            DataType::String | DataType::Bytes => None,
         }
     }
     /// Returns the total number of elements in the array.
@@ -0,0 +1,230 @@
+use crate::adaptor::*;
+use crate::convert::cast::{narrow_integer, read_signed, write_float, write_signed};
+use crate::error::ArrayObjectError;
+use crate::storage::*;
+
+/// The resolution of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl TimeUnit {
+    /// Returns the length of the unit in nanoseconds.
+    pub fn nanos(&self) -> i128 {
+        match self {
+            TimeUnit::Second => 1_000_000_000,
+            TimeUnit::Millisecond => 1_000_000,
+            TimeUnit::Microsecond => 1_000,
+            TimeUnit::Nanosecond => 1,
+        }
+    }
+}
+
+/// The meaning of signed integers. The unit is recorded in the footer and checked when the data is restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// The time elapsed since 1970-01-01T00:00:00Z.
+    Timestamp(TimeUnit),
+    /// The days elapsed since 1970-01-01.
+    Date,
+    /// The length of time.
+    Duration(TimeUnit),
+    /// The decimal number of the integer times ten to the power of the exponent.
+    Decimal(i32),
+}
+
+impl Unit {
+    /// Returns the length of the unit in nanoseconds.
+    fn nanos(&self) -> i128 {
+        match self {
+            Unit::Timestamp(x) | Unit::Duration(x) => x.nanos(),
+            Unit::Date => 86_400_000_000_000,
+            Unit::Decimal(_) => unreachable!(),
+        }
+    }
+    fn is_compatible(&self, other: &Unit) -> bool {
+        matches!(
+            (self, other),
+            (Unit::Timestamp(_), Unit::Timestamp(_))
+                | (Unit::Date, Unit::Date)
+                | (Unit::Duration(_), Unit::Duration(_))
+        )
+    }
+}
+
+impl ArrayObject {
+    /// Returns the unit of the elements if any.
+    pub fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+    /// Attaches the unit to an array of signed integers.
+    ///
+    /// ```
+    /// use array_object::*;
+    /// let obj: ArrayObject = vec![0i64, 86400].into();
+    /// let obj = obj.with_unit(Unit::Timestamp(TimeUnit::Second)).unwrap();
+    /// assert_eq!(obj.unit(), Some(Unit::Timestamp(TimeUnit::Second)));
+    /// ```
+    pub fn with_unit(mut self, unit: Unit) -> Result<Self, ArrayObjectError> {
+        if self.datatype != DataType::SignedInteger {
+            return Err(ArrayObjectError::WrongDataType(
+                DataType::SignedInteger,
+                self.shape.len(),
+            ));
+        }
+        self.unit = Some(unit);
+        Ok(self)
+    }
+    /// Applies the exponent of decimal numbers, giving real numbers if `real` is true and integers otherwise. The integers are checked to be exact unless `lossy` is true. The other arrays are returned as they are.
+    pub(crate) fn descale(self, real: bool, lossy: bool) -> Result<Self, ArrayObjectError> {
+        let Some(Unit::Decimal(exponent)) = self.unit else {
+            return Ok(self);
+        };
+        let mut obj = narrow_integer(self)?;
+        let mantissas = read_signed(&obj);
+        if real {
+            let factor = 10f64.powi(exponent.abs());
+            obj.data = write_float(
+                mantissas
+                    .into_iter()
+                    .map(|x| match exponent {
+                        0.. => x as f64 * factor,
+                        _ => x as f64 / factor,
+                    })
+                    .collect(),
+            );
+            obj.datatype = DataType::Real;
+        } else {
+            let factor = 10i128.checked_pow(exponent.unsigned_abs());
+            obj.data = write_signed(
+                mantissas
+                    .into_iter()
+                    .map(|x| match (exponent, factor) {
+                        (0.., _) => factor
+                            .and_then(|f| x.checked_mul(f))
+                            .ok_or(ArrayObjectError::Overflow),
+                        (_, Some(f)) if lossy || x % f == 0 => Ok(x / f),
+                        (_, None) if lossy || x == 0 => Ok(0),
+                        _ => Err(ArrayObjectError::LossyConversion),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+        obj.unit = None;
+        Ok(obj)
+    }
+    /// Creates an array of signed integers with the unit.
+    pub(crate) fn from_time(val: Vec<i128>, shape: Vec<u64>, unit: Unit) -> Self {
+        ArrayObject {
+            data: write_signed(val),
+            shape,
+            datatype: DataType::SignedInteger,
+            validity: None,
+            unit: Some(unit),
+        }
+    }
+    /// Reads the elements in the given unit. A finer unit is accepted only when the values are exactly representable.
+    pub(crate) fn into_time(self, unit: Unit) -> Result<VecShape<i128>, ArrayObjectError> {
+        let val = self.into_valid()?;
+        let found = match val.unit {
+            Some(found) if found.is_compatible(&unit) => found,
+            found => return Err(ArrayObjectError::WrongUnit(found, unit)),
+        };
+        let data = if found.nanos() >= unit.nanos() {
+            let factor = found.nanos() / unit.nanos();
+            read_signed(&val)
+                .into_iter()
+                .map(|x| x.checked_mul(factor).ok_or(ArrayObjectError::Overflow))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            let factor = unit.nanos() / found.nanos();
+            read_signed(&val)
+                .into_iter()
+                .map(|x| match x % factor {
+                    0 => Ok(x / factor),
+                    _ => Err(ArrayObjectError::LossyConversion),
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        Ok(VecShape(data, val.shape))
+    }
+}
+
+/// Implements the conversions of a time type stored as signed integers in the unit. `$into` maps a reference into an integer and `$from` maps it back, returning None if it is out of range.
+macro_rules! time_impl {
+    ($ty:ty, $unit:expr, $into:expr, $from:expr) => {
+        impl From<$ty> for ArrayObject {
+            fn from(val: $ty) -> Self {
+                ArrayObject::from_time(vec![$into(&val)], vec![], $unit)
+            }
+        }
+        impl From<Vec<$ty>> for ArrayObject {
+            fn from(val: Vec<$ty>) -> Self {
+                let shape = vec![val.len() as u64];
+                ArrayObject::from_time(val.iter().map($into).collect(), shape, $unit)
+            }
+        }
+        impl TryFrom<VecShape<$ty>> for ArrayObject {
+            type Error = ArrayObjectError;
+            fn try_from(VecShape(val, shape): VecShape<$ty>) -> Result<Self, Self::Error> {
+                if val.len() != shape.product() as usize {
+                    return Err(ArrayObjectError::NumberOfElementsMismatch(
+                        val.len(),
+                        shape.product() as usize,
+                    ));
+                }
+                Ok(ArrayObject::from_time(
+                    val.iter().map($into).collect(),
+                    shape,
+                    $unit,
+                ))
+            }
+        }
+        impl TryFrom<ArrayObject> for VecShape<$ty> {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if val.shape.is_empty() {
+                    return Err(ArrayObjectError::WrongDataType(val.datatype, 0));
+                }
+                let VecShape(data, shape) = val.into_time($unit)?;
+                let data = data
+                    .into_iter()
+                    .map(|x| $from(x).ok_or(ArrayObjectError::Overflow))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(VecShape(data, shape))
+            }
+        }
+        impl TryFrom<ArrayObject> for Vec<$ty> {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if val.shape.len() != 1 {
+                    return Err(ArrayObjectError::WrongDataType(
+                        val.datatype,
+                        val.shape.len(),
+                    ));
+                }
+                let VecShape::<$ty>(data, _) = val.try_into()?;
+                Ok(data)
+            }
+        }
+        impl TryFrom<ArrayObject> for $ty {
+            type Error = ArrayObjectError;
+            fn try_from(val: ArrayObject) -> Result<Self, Self::Error> {
+                if !val.shape.is_empty() {
+                    return Err(ArrayObjectError::WrongDataType(
+                        val.datatype,
+                        val.shape.len(),
+                    ));
+                }
+                let VecShape(data, _) = val.into_time($unit)?;
+                $from(data[0]).ok_or(ArrayObjectError::Overflow)
+            }
+        }
+    };
+}
+
+pub(crate) use time_impl;
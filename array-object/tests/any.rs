@@ -0,0 +1,95 @@
+use array_object::adaptor::*;
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn narrowest_type() {
+    let original: Vec<_> = (-128..128).map(|i| i as i64).collect();
+    let obj: ArrayObject = original.clone().into();
+    let any = obj.into_any().unwrap();
+    let restored: Vec<_> = original.iter().map(|&x| x as i8).collect();
+    assert_eq!(any, AnyArray::I8(VecShape(restored, vec![256])));
+
+    let obj: ArrayObject = 70000u32.into();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(
+        unpacked.into_any().unwrap(),
+        AnyArray::U32(VecShape(vec![70000], vec![]))
+    );
+
+    let obj: ArrayObject = vec![0.5f64, 1.25].into();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::F32(VecShape(vec![0.5, 1.25], vec![2]))
+    );
+
+    let obj: ArrayObject = vec![Complex::new(0.1f64, 0.5)].into();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::C64(VecShape(vec![Complex::new(0.1, 0.5)], vec![1]))
+    );
+
+    let obj: ArrayObject = vec!["a", "b"].into();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::Str(VecShape(vec!["a".to_string(), "b".to_string()], vec![2]))
+    );
+}
+
+#[test]
+fn unit() {
+    let obj = ArrayObject::from(vec![1i64, 2])
+        .with_unit(Unit::Decimal(-2))
+        .unwrap();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::F64(VecShape(vec![0.01, 0.02], vec![2]))
+    );
+    let obj = ArrayObject::from(vec![15i64, -5])
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::F32(VecShape(vec![1.5, -0.5], vec![2]))
+    );
+    let obj = ArrayObject::from(3i64).with_unit(Unit::Decimal(3)).unwrap();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::F32(VecShape(vec![3000.0], vec![]))
+    );
+
+    let obj = ArrayObject::from(vec![0i64, 86400])
+        .with_unit(Unit::Timestamp(TimeUnit::Second))
+        .unwrap();
+    assert_eq!(
+        obj.into_any().unwrap(),
+        AnyArray::I32(VecShape(vec![0, 86400], vec![2]))
+    );
+}
+
+#[test]
+fn round_trip() {
+    let originals: Vec<ArrayObject> = vec![
+        VecShape(vec![1u16, 2, 3, 4, 5, 60000], vec![2, 3])
+            .try_into()
+            .unwrap(),
+        (-5i32).into(),
+        vec![0.1f64, -2.0].into(),
+        Vec::<f32>::new().into(),
+        "test".into(),
+    ];
+    for original in originals {
+        let binary = original.clone().pack();
+        let any = ArrayObject::unpack(binary).unwrap().into_any().unwrap();
+        let restored: ArrayObject = any.try_into().unwrap();
+        assert_eq!(original.shape(), restored.shape());
+        assert_eq!(original.clone().pack(), restored.pack());
+    }
+}
+
+#[test]
+fn malformed_any() {
+    let any = AnyArray::U8(VecShape(vec![1, 2, 3], vec![2, 2]));
+    assert!(ArrayObject::try_from(any).is_err());
+}
@@ -0,0 +1,51 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn bytes_with_marker() {
+    let original = vec![vec![0xFFu8, 0x00, 0xFE], vec![], vec![0xFF; 200]];
+    let obj: ArrayObject = original.clone().into();
+    assert_eq!(obj.datatype(), DataType::Bytes);
+    let binary = obj.clone().pack();
+    assert_eq!(DataType::describe_footer(&binary), "Bytes array");
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(unpacked, obj);
+    let restored: Vec<Vec<u8>> = unpacked.try_into().unwrap();
+    assert_eq!(restored, original);
+
+    let restored: Vec<Vec<u8>> = ArrayObject::unpack(obj.pack_as_it_is())
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn bytes_dictionary() {
+    let blobs = [vec![0xABu8; 32], vec![0xCD; 32], vec![0xFF; 32]];
+    let original: Vec<Vec<u8>> = (0..300).map(|i| blobs[i % 3].clone()).collect();
+    let obj: ArrayObject = VecShape(original.clone(), vec![100, 3]).try_into().unwrap();
+    let binary = obj.clone().pack();
+    assert!(binary.len() < 500);
+    let VecShape::<Vec<u8>>(restored, shape) =
+        ArrayObject::unpack(binary).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+    assert_eq!(shape, vec![100, 3]);
+
+    let objs = obj.split(0, 4).unwrap();
+    assert_eq!(objs[1].shape(), vec![25, 3]);
+    assert_eq!(objs.concat(0).unwrap().len(), 300);
+}
+
+#[test]
+fn nullable_bytes() {
+    let original = vec![Some(vec![1u8, 255]), None, Some(vec![])];
+    let obj: ArrayObject = original.clone().into();
+    assert_eq!(
+        format!("{}", obj),
+        "[b\"\\x01\\xff\",        None,         b\"\"]"
+    );
+    let restored: Vec<Option<Vec<u8>>> =
+        ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+}
@@ -0,0 +1,102 @@
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn promotion() {
+    let obj: ArrayObject = vec![1u8, 200].into();
+    let obj = obj
+        .cast(DataType::SignedInteger, CastOption::Checked)
+        .unwrap();
+    let restored: Vec<i16> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![1, 200]);
+
+    let obj = obj.cast(DataType::Real, CastOption::Checked).unwrap();
+    let restored: Vec<f32> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![1.0, 200.0]);
+
+    let obj = obj.cast(DataType::Complex, CastOption::Checked).unwrap();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let restored: Vec<Complex<f64>> = unpacked.try_into().unwrap();
+    assert_eq!(
+        restored,
+        vec![Complex::new(1.0, 0.0), Complex::new(200.0, 0.0)]
+    );
+
+    let obj: ArrayObject = (-7i64).into();
+    let obj = obj.cast(DataType::Complex, CastOption::Checked).unwrap();
+    let restored: Complex<f32> = obj.try_into().unwrap();
+    assert_eq!(restored, Complex::new(-7.0, 0.0));
+
+    let obj: ArrayObject = vec![u64::MAX - 1].into();
+    assert!(obj.cast(DataType::Real, CastOption::Checked).is_err());
+}
+
+#[test]
+fn demotion() {
+    let obj: ArrayObject = vec![Complex::new(-3.0f64, 0.0), Complex::new(4.0, 0.0)].into();
+    let obj = obj.cast(DataType::Real, CastOption::Checked).unwrap();
+    let restored: Vec<f64> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![-3.0, 4.0]);
+    let obj = obj
+        .cast(DataType::SignedInteger, CastOption::Checked)
+        .unwrap();
+    let restored: Vec<i8> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![-3, 4]);
+    assert!(
+        obj.cast(DataType::UnsignedInteger, CastOption::Checked)
+            .is_err()
+    );
+
+    let obj: ArrayObject = vec![Complex::new(1.5f64, 0.5)].into();
+    assert!(
+        obj.clone()
+            .cast(DataType::Real, CastOption::Checked)
+            .is_err()
+    );
+    assert!(
+        obj.clone()
+            .cast(DataType::UnsignedInteger, CastOption::Checked)
+            .is_err()
+    );
+    let obj = obj
+        .cast(DataType::UnsignedInteger, CastOption::Lossy)
+        .unwrap();
+    let restored: Vec<u8> = obj.try_into().unwrap();
+    assert_eq!(restored, vec![1]);
+
+    let obj: ArrayObject = vec!["1"].into();
+    assert!(obj.cast(DataType::Real, CastOption::Checked).is_err());
+}
+
+#[test]
+fn real_boundary() {
+    let limit = 2f64.powi(127);
+    let obj: ArrayObject = limit.into();
+    assert!(
+        obj.clone()
+            .cast(DataType::SignedInteger, CastOption::Checked)
+            .is_err()
+    );
+    let saturated: i128 = obj
+        .cast(DataType::SignedInteger, CastOption::Lossy)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(saturated, i128::MAX);
+
+    let obj: ArrayObject = vec![-limit, limit - 2f64.powi(74)].into();
+    let restored: Vec<i128> = obj
+        .cast(DataType::SignedInteger, CastOption::Checked)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(restored, vec![i128::MIN, i128::MAX - (1 << 74) + 1]);
+
+    let obj: ArrayObject = 2f64.powi(63).into();
+    let obj = obj
+        .cast(DataType::SignedInteger, CastOption::Checked)
+        .unwrap();
+    assert!(i64::try_from(obj.clone()).is_err());
+    assert_eq!(u64::try_from(obj).unwrap(), 1 << 63);
+}
@@ -0,0 +1,43 @@
+use array_object::*;
+
+#[test]
+fn checksum() {
+    let obj: ArrayObject = vec![1.5f64, -2.25, 3.0e10].into();
+    let option = PackOption {
+        checksum: true,
+        ..Default::default()
+    };
+    let binary = obj.clone().pack_with(option);
+    assert_eq!(DataType::describe_footer(&binary), "Array with checksum");
+    assert_eq!(ArrayObject::unpack(binary.clone()).unwrap(), obj);
+
+    let mut broken = binary.clone();
+    broken[3] ^= 0b0000_0100;
+    let err = ArrayObject::unpack(broken.clone()).err().unwrap();
+    assert!(err.to_string().starts_with("The checksum does not match"));
+    let unpacked = ArrayObject::unpack_with(broken, UnpackOption::SkipVerification).unwrap();
+    assert_ne!(unpacked, obj);
+
+    let option = PackOption {
+        compress: false,
+        checksum: true,
+    };
+    let obj: ArrayObject = vec![Some("a".to_string()), None].into();
+    let binary = obj.clone().pack_with(option);
+    assert_eq!(binary.len(), obj.clone().pack_as_it_is().len() + 9);
+    assert_eq!(ArrayObject::unpack(binary).unwrap(), obj);
+    assert_eq!(obj.clone().pack_with(PackOption::default()), obj.pack());
+}
+
+#[test]
+fn checksum_layout() {
+    let obj: ArrayObject = "123456789".to_string().into();
+    let option = PackOption {
+        checksum: true,
+        ..Default::default()
+    };
+    let binary = obj.pack_with(option);
+    // The CRC-32C of the packed string is 0x00b5bb8b, written as [CRC32C, checksum] in the metadata.
+    let footer = vec![0x05, 0xd6, 0xf7, 0x8b, 0x00, 0x05, 0x04, 0xe0];
+    assert_eq!(binary, [b"123456789".to_vec(), vec![0xc0], footer].concat());
+}
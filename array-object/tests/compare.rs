@@ -0,0 +1,112 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn semantically_eq() {
+    let original: ArrayObject = vec![1u64, 2, 3].into();
+    let binary = original.clone().pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_ne!(original, unpacked);
+    assert!(original.semantically_eq(&unpacked));
+
+    let a: ArrayObject = vec![0.5f32, f32::NAN].into();
+    let b: ArrayObject = vec![0.5f64, f64::NAN].into();
+    assert!(a.semantically_eq(&b));
+    assert!(!a.approx_eq(&b, 0.0, 0.0));
+
+    let c: ArrayObject = vec![1i64, 2, 3].into();
+    assert!(!original.semantically_eq(&c));
+}
+
+#[test]
+fn large_integers() {
+    let a: ArrayObject = vec![u128::MAX, 0].into();
+    let b: ArrayObject = vec![i128::MAX, 0].into();
+    let diff = a.diff(&b, 0.0, 0.0, 10);
+    assert_eq!(diff.mismatches, 1);
+    assert_eq!(diff.indices, vec![vec![0]]);
+    let c: ArrayObject = vec![u128::MAX - 1, 0].into();
+    assert!(!a.semantically_eq(&c));
+    assert!(a.semantically_eq(&a.clone()));
+
+    let d: ArrayObject = vec![i128::MIN, 5].into();
+    let e: ArrayObject = vec![i128::MAX, 5].into();
+    assert_eq!(d.diff(&e, 0.0, 0.0, 10).max_abs_error, u128::MAX as f64);
+}
+
+#[test]
+fn approx_eq() {
+    let a: ArrayObject = vec![1.0f64, 100.0].into();
+    let b: ArrayObject = vec![1.0f64 + 1e-9, 100.0 + 1e-7].into();
+    assert!(a.approx_eq(&b, 1e-8, 0.0));
+    assert!(!a.approx_eq(&b, 1e-10, 0.0));
+    assert!(a.approx_eq(&b, 0.0, 1e-6));
+
+    let c: ArrayObject = vec![Complex::new(1.0f64, 1.0)].into();
+    let d: ArrayObject = vec![Complex::new(1.0f64, 1.0 + 1e-12)].into();
+    assert!(c.approx_eq(&d, 1e-9, 0.0));
+}
+
+#[test]
+fn diff() {
+    let a: ArrayObject = VecShape(vec![1.0f64, 2.0, 3.0, 4.0], vec![2, 2])
+        .try_into()
+        .unwrap();
+    let b: ArrayObject = VecShape(vec![1.0f64, 2.5, 3.0, 2.0], vec![2, 2])
+        .try_into()
+        .unwrap();
+    let diff = a.diff(&b, 0.0, 0.0, 1);
+    assert!(!diff.is_match());
+    assert_eq!(diff.mismatches, 2);
+    assert_eq!(diff.max_abs_error, 2.0);
+    assert_eq!(diff.max_rel_error, 1.0);
+    assert_eq!(diff.indices, vec![vec![0, 1]]);
+
+    let c: ArrayObject = vec![1u8, 2, 3, 4].into();
+    let diff = a.diff(&c, 0.0, 0.0, 10);
+    assert_eq!(diff.shape, Some((vec![2, 2], vec![4])));
+    assert_eq!(
+        diff.datatype,
+        Some((DataType::Real, DataType::UnsignedInteger))
+    );
+
+    let d: ArrayObject = VecShape(vec![1u8, 2, 3, 4], vec![2, 2]).try_into().unwrap();
+    let diff = a.diff(&d, 0.0, 0.0, 10);
+    assert_eq!(diff.mismatches, 0);
+    assert!(!diff.is_match());
+
+    let e: ArrayObject = vec!["a", "b", "c"].into();
+    let f: ArrayObject = vec!["a", "x", "c"].into();
+    assert_eq!(e.diff(&f, 0.0, 0.0, 10).indices, vec![vec![1]]);
+}
+
+#[test]
+fn unit() {
+    let a = ArrayObject::from(vec![100i64, 200])
+        .with_unit(Unit::Decimal(-2))
+        .unwrap();
+    let b: ArrayObject = vec![100i64, 200].into();
+    assert!(!a.semantically_eq(&b));
+    let diff = a.diff(&b, 0.0, 0.0, 10);
+    assert_eq!(diff.unit, Some((Some(Unit::Decimal(-2)), None)));
+    assert_eq!(diff.mismatches, 0);
+    assert!(a.semantically_eq(&a.clone()));
+
+    let s = ArrayObject::from(vec![1i64, 2])
+        .with_unit(Unit::Timestamp(TimeUnit::Second))
+        .unwrap();
+    let ms = s
+        .clone()
+        .with_unit(Unit::Timestamp(TimeUnit::Millisecond))
+        .unwrap();
+    assert!(!s.semantically_eq(&ms));
+    assert!(!s.approx_eq(&ms, 1.0, 1.0));
+    assert_eq!(
+        s.diff(&ms, 0.0, 0.0, 10).unit,
+        Some((
+            Some(Unit::Timestamp(TimeUnit::Second)),
+            Some(Unit::Timestamp(TimeUnit::Millisecond))
+        ))
+    );
+}
@@ -115,3 +115,18 @@ fn array() {
     let restored: [Complex64; 128] = unpacked.try_into().unwrap();
     assert_eq!(original, restored);
 }
+
+#[test]
+fn from_real_and_integer() {
+    let original: Vec<_> = (-128..128).map(|i| i as f64 * PI).collect();
+    let obj: ArrayObject = original.clone().into();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let restored: Vec<Complex64> = unpacked.try_into().unwrap();
+    let expected: Vec<_> = original.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+    assert_eq!(expected, restored);
+
+    let obj: ArrayObject = 5u16.into();
+    let adaptor::Pair(re, im) = obj.try_into().unwrap();
+    assert_eq!((re, im), (5f32, 0f32));
+}
@@ -0,0 +1,144 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn reduction() {
+    let obj: ArrayObject = VecShape(vec![3i32, -1, 4, 1, -5, 9], vec![2, 3])
+        .try_into()
+        .unwrap();
+    let sum: i32 = obj.sum(None).unwrap().try_into().unwrap();
+    assert_eq!(sum, 11);
+    let sum: Vec<i32> = obj.sum(Some(1)).unwrap().try_into().unwrap();
+    assert_eq!(sum, vec![6, 5]);
+    let min: Vec<i8> = obj.min(Some(0)).unwrap().try_into().unwrap();
+    assert_eq!(min, vec![1, -5, 4]);
+    let max: i64 = obj.max(None).unwrap().try_into().unwrap();
+    assert_eq!(max, 9);
+    let argmax: u64 = obj.argmax(None).unwrap().try_into().unwrap();
+    assert_eq!(argmax, 5);
+    let argmax: Vec<usize> = obj.argmax(Some(1)).unwrap().try_into().unwrap();
+    assert_eq!(argmax, vec![2, 2]);
+    let mean: Vec<f64> = obj.mean(Some(0)).unwrap().try_into().unwrap();
+    assert_eq!(mean, vec![2.0, -3.0, 6.5]);
+    assert!(obj.sum(Some(2)).is_err());
+
+    let obj: ArrayObject = vec![0.5f64, f64::NAN, -2.0, 0.0].into();
+    let nan: u32 = obj.count_nan(None).unwrap().try_into().unwrap();
+    assert_eq!(nan, 1);
+    let nonzero: u32 = obj.count_nonzero(None).unwrap().try_into().unwrap();
+    assert_eq!(nonzero, 3);
+    let min: f64 = obj.min(None).unwrap().try_into().unwrap();
+    assert_eq!(min, -2.0);
+
+    let obj: ArrayObject = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -4.0)].into();
+    let mean: Complex<f64> = obj.mean(None).unwrap().try_into().unwrap();
+    assert_eq!(mean, Complex::new(2.0, -1.0));
+    assert!(obj.max(None).is_err());
+
+    let obj: ArrayObject = Vec::<u8>::new().into();
+    assert!(obj.max(None).is_err());
+    let sum: u8 = obj.sum(None).unwrap().try_into().unwrap();
+    assert_eq!(sum, 0);
+}
+
+#[test]
+fn elementwise() {
+    let a: ArrayObject = vec![1u8, 2, 3].into();
+    let b: ArrayObject = vec![-1i64, -2, -3].into();
+    let c: Vec<i32> = a.add(&b).unwrap().try_into().unwrap();
+    assert_eq!(c, vec![0, 0, 0]);
+    let c: Vec<i32> = a.mul(&b).unwrap().try_into().unwrap();
+    assert_eq!(c, vec![-1, -4, -9]);
+    let c: Vec<i8> = a.sub(&vec![2u8, 2, 2].into()).unwrap().try_into().unwrap();
+    assert_eq!(c, vec![-1, 0, 1]);
+    let c = a.sub(&vec![1u8, 1, 1].into()).unwrap();
+    assert_eq!(c.datatype(), DataType::UnsignedInteger);
+    let big: ArrayObject = vec![u128::MAX].into();
+    assert!(big.sub(&vec![u128::MAX - 1].into()).is_ok());
+    assert!(ArrayObject::from(0u8).sub(&big).is_err());
+
+    let d: ArrayObject = vec![0.5f64, 0.25, 0.125].into();
+    let e: Vec<f64> = a.sub(&d).unwrap().try_into().unwrap();
+    assert_eq!(e, vec![0.5, 1.75, 2.875]);
+
+    let f: ArrayObject = Complex::new(0.0f64, 1.0).into();
+    let g: Vec<Complex<f64>> = a.mul(&f).unwrap().try_into().unwrap();
+    assert_eq!(g[2], Complex::new(0.0, 3.0));
+
+    let h: Vec<u16> = a.scale(100u8).unwrap().try_into().unwrap();
+    assert_eq!(h, vec![100, 200, 300]);
+
+    assert!(a.add(&vec![1u8, 2].into()).is_err());
+    assert!(a.add(&"test".into()).is_err());
+}
+
+#[test]
+fn elementwise_unit() {
+    let a = ArrayObject::from(vec![10i64, 20])
+        .with_unit(Unit::Duration(TimeUnit::Second))
+        .unwrap();
+    let b = ArrayObject::from(vec![3i64, 5])
+        .with_unit(Unit::Duration(TimeUnit::Second))
+        .unwrap();
+    let diff = a.sub(&b).unwrap();
+    assert_eq!(diff.unit(), Some(Unit::Duration(TimeUnit::Second)));
+    let diff: Vec<std::time::Duration> = diff.try_into().unwrap();
+    assert_eq!(diff[1], std::time::Duration::from_secs(15));
+    let c = b
+        .clone()
+        .with_unit(Unit::Duration(TimeUnit::Millisecond))
+        .unwrap();
+    assert!(a.add(&c).is_err());
+    assert!(a.add(&vec![1i64, 2].into()).is_err());
+    let doubled = a.mul(&2i64.into()).unwrap();
+    assert_eq!(doubled.unit(), Some(Unit::Duration(TimeUnit::Second)));
+
+    let x = ArrayObject::from(vec![15i64, -2])
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    let y = ArrayObject::from(5i64)
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    let sum: Vec<f64> = x.add(&y).unwrap().try_into().unwrap();
+    assert_eq!(sum, vec![2.0, 0.3]);
+    let product = x.mul(&y).unwrap();
+    assert_eq!(product.unit(), Some(Unit::Decimal(-2)));
+    let product: Vec<f64> = product.try_into().unwrap();
+    assert_eq!(product, vec![0.75, -0.1]);
+    let scaled: Vec<f64> = x.mul(&0.5f64.into()).unwrap().try_into().unwrap();
+    assert_eq!(scaled, vec![0.75, -0.1]);
+}
+
+#[test]
+fn timestamp_unit() {
+    let t = ArrayObject::from(vec![100i64, 250])
+        .with_unit(Unit::Timestamp(TimeUnit::Millisecond))
+        .unwrap();
+    let u = ArrayObject::from(vec![40i64, 50])
+        .with_unit(Unit::Timestamp(TimeUnit::Millisecond))
+        .unwrap();
+    let elapsed = t.sub(&u).unwrap();
+    assert_eq!(elapsed.unit(), Some(Unit::Duration(TimeUnit::Millisecond)));
+    let elapsed: Vec<i64> = elapsed.try_into().unwrap();
+    assert_eq!(elapsed, vec![60, 200]);
+
+    let d = ArrayObject::from(vec![5i64, 10])
+        .with_unit(Unit::Duration(TimeUnit::Millisecond))
+        .unwrap();
+    let later = t.add(&d).unwrap();
+    assert_eq!(later.unit(), Some(Unit::Timestamp(TimeUnit::Millisecond)));
+    assert_eq!(d.add(&t).unwrap().unit(), later.unit());
+    let earlier = t.sub(&d).unwrap();
+    assert_eq!(earlier.unit(), Some(Unit::Timestamp(TimeUnit::Millisecond)));
+    let earlier: Vec<i64> = earlier.try_into().unwrap();
+    assert_eq!(earlier, vec![95, 240]);
+    assert!(d.sub(&t).is_err());
+
+    assert!(matches!(
+        t.add(&u),
+        Err(ArrayObjectError::UndefinedOperation("add", _))
+    ));
+    let v = u.with_unit(Unit::Timestamp(TimeUnit::Second)).unwrap();
+    assert!(t.sub(&v).is_err());
+}
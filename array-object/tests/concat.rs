@@ -0,0 +1,98 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn concat_axis() {
+    let a: ArrayObject = VecShape(vec![0u8, 1, 2, 3], vec![2, 2]).try_into().unwrap();
+    let b: ArrayObject = VecShape(vec![1000u32, 2000], vec![2, 1])
+        .try_into()
+        .unwrap();
+    let obj = vec![a, b].concat(1).unwrap();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let VecShape::<u32>(restored, shape) = unpacked.try_into().unwrap();
+    assert_eq!(restored, vec![0, 1, 1000, 2, 3, 2000]);
+    assert_eq!(shape, vec![2, 3]);
+
+    let a: ArrayObject = vec![1.5f32, 2.5].into();
+    let b: ArrayObject = vec![0.1f64].into();
+    let obj = vec![a, b].concat(0).unwrap();
+    let restored: Vec<f64> = obj.try_into().unwrap();
+    assert_eq!(restored, vec![1.5, 2.5, 0.1]);
+
+    let a: ArrayObject = VecShape(vec!["a".to_string(), "b".to_string()], vec![2, 1])
+        .try_into()
+        .unwrap();
+    let b: ArrayObject = VecShape(vec!["cc".to_string(), "".to_string()], vec![2, 1])
+        .try_into()
+        .unwrap();
+    let obj = vec![a, b].concat(1).unwrap();
+    let restored: VecShape<String> = obj.try_into().unwrap();
+    assert_eq!(restored.0, vec!["a", "cc", "b", ""]);
+}
+
+#[test]
+fn stack_and_unstack() {
+    let a: ArrayObject = vec![-1i8, 2].into();
+    let b: ArrayObject = vec![-300i32, 400].into();
+    let obj = vec![a, b].stack(1).unwrap();
+    assert_eq!(obj.shape(), vec![2, 2]);
+    let objs = obj.clone().unstack(1).unwrap();
+    let restored: Vec<i32> = objs[1].clone().try_into().unwrap();
+    assert_eq!(restored, vec![-300, 400]);
+
+    let VecShape::<i32>(restored, _) = obj.try_into().unwrap();
+    assert_eq!(restored, vec![-1, -300, 2, 400]);
+}
+
+#[test]
+fn split() {
+    let original: Vec<_> = (0..24).map(|i| i as u16 * 100).collect();
+    let obj: ArrayObject = VecShape(original.clone(), vec![2, 4, 3])
+        .try_into()
+        .unwrap();
+    let objs = obj.clone().split(1, 2).unwrap();
+    assert_eq!(objs.len(), 2);
+    assert_eq!(objs[0].shape(), vec![2, 2, 3]);
+    let restored = objs.concat(1).unwrap();
+    assert_eq!(obj, restored);
+
+    assert!(obj.clone().split(1, 3).is_err());
+    assert!(obj.split(3, 1).is_err());
+}
+
+#[test]
+fn errors() {
+    let objs: Vec<ArrayObject> = vec![];
+    assert!(objs.clone().try_concat().is_err());
+    assert!(objs.concat(0).is_err());
+
+    let a: ArrayObject = vec![1u8, 2].into();
+    let b: ArrayObject = vec![1i8, 2].into();
+    assert!(vec![a.clone(), b].concat(0).is_err());
+
+    let c: ArrayObject = vec![1u8, 2, 3].into();
+    assert!(vec![a.clone(), c.clone()].stack(0).is_err());
+    assert!(vec![a, c].concat(0).is_ok());
+}
+
+#[test]
+fn malformed_strings() {
+    // Three strings are joined while the shape says two, which is rejected rather than split or concatenated.
+    let binary = [b"a\xffb\xffc".to_vec(), vec![2, 0xc1]].concat();
+    assert!(ArrayObject::unpack(binary).is_err());
+
+    // The empty strings and the empty arrays are still counted correctly.
+    let a: ArrayObject = vec![String::new()].into();
+    let b: ArrayObject = Vec::<String>::new().into();
+    let joined = vec![a.clone(), b, a].concat(0).unwrap();
+    let restored: Vec<String> = joined.clone().try_into().unwrap();
+    assert_eq!(restored, vec!["", ""]);
+    let parts = joined.split(0, 2).unwrap();
+    let restored: Vec<String> = parts[1].clone().try_into().unwrap();
+    assert_eq!(restored, vec![""]);
+
+    let c: ArrayObject = vec![Vec::<u8>::new(), vec![0xff]].into();
+    let parts = c.clone().split(0, 2).unwrap();
+    assert!(parts.concat(0).unwrap().semantically_eq(&c));
+}
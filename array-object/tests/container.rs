@@ -0,0 +1,133 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn container() {
+    let path = std::env::temp_dir().join("test_container.bin");
+    let mut container = Container::create(&path).unwrap();
+    assert!(container.is_empty());
+    let matrix: ArrayObject = VecShape((0..12i32).collect(), vec![3, 4])
+        .try_into()
+        .unwrap();
+    container.append("matrix", matrix.clone()).unwrap();
+    container
+        .append("mask", vec![Some(1u8), None].into())
+        .unwrap();
+    let err = container.append("mask", 1u8.into()).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "The container already has an object named `mask`."
+    );
+    drop(container);
+
+    let mut container = Container::open(&path).unwrap();
+    assert_eq!(container.names(), &["matrix", "mask"]);
+    assert!(container.read("matrix").unwrap().semantically_eq(&matrix));
+    let mask: Vec<Option<u8>> = container.read_as("mask").unwrap();
+    assert_eq!(mask, vec![Some(1), None]);
+    let err = container.read("missing").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "The container has no object named `missing`."
+    );
+
+    // The existing objects are kept as they are.
+    let mask: ArrayObject = vec![Some(1u8), None].into();
+    let len = matrix.clone().pack().len() + mask.clone().pack().len();
+    let before = std::fs::read(&path).unwrap();
+    container
+        .append("names", vec!["a".to_string(), "b".to_string()].into())
+        .unwrap();
+    let after = std::fs::read(&path).unwrap();
+    assert_eq!(after[..len], before[..len]);
+    // The staged copy is truncated after it is moved over the old index.
+    let fresh = std::env::temp_dir().join("test_container_fresh.bin");
+    let mut container = Container::create(&fresh).unwrap();
+    container.append("matrix", matrix.clone()).unwrap();
+    container.append("mask", mask).unwrap();
+    container
+        .append("names", vec!["a".to_string(), "b".to_string()].into())
+        .unwrap();
+    assert_eq!(std::fs::read(&fresh).unwrap(), after);
+
+    let container = Container::open(&path).unwrap();
+    assert_eq!(container.len(), 3);
+    let names: Vec<String> = container.read_as("names").unwrap();
+    assert_eq!(names, vec!["a", "b"]);
+    assert!(container.read("matrix").unwrap().semantically_eq(&matrix));
+}
+
+#[test]
+fn broken_container() {
+    let path = std::env::temp_dir().join("test_container_broken.bin");
+    std::fs::write(&path, vec![1u8, 2, 3]).unwrap();
+    assert!(Container::open(&path).is_err());
+    let obj: ArrayObject = vec![1u8, 2, 3].into();
+    std::fs::write(&path, obj.pack()).unwrap();
+    assert!(Container::open(&path).is_err());
+    assert!(Container::open(std::env::temp_dir().join("test_container_missing.bin")).is_err());
+}
+
+#[test]
+fn concurrent_read() {
+    let path = std::env::temp_dir().join("test_container_concurrent.bin");
+    let mut container = Container::create(&path).unwrap();
+    for i in 0..8u64 {
+        let obj: ArrayObject = (0..1000).map(|x| x * i).collect::<Vec<u64>>().into();
+        container.append(&format!("x{i}"), obj).unwrap();
+    }
+    let container = &container;
+    std::thread::scope(|s| {
+        for i in 0..8u64 {
+            s.spawn(move || {
+                for _ in 0..20 {
+                    let x: Vec<u64> = container.read_as(&format!("x{i}")).unwrap();
+                    assert_eq!(x[999], 999 * i);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn interrupted_append() {
+    let path = std::env::temp_dir().join("test_container_interrupted.bin");
+    let mut container = Container::create(&path).unwrap();
+    container.append("x", vec![1u8, 2, 3].into()).unwrap();
+    container.append("y", "second".to_string().into()).unwrap();
+    drop(container);
+    let complete = std::fs::read(&path).unwrap();
+
+    // The staged object and a part of its index are written after the end of the file, but not the whole trailer.
+    let staged = ArrayObject::from(vec![4.0f64, 5.0]).pack();
+    let mut broken = [complete.clone(), staged].concat();
+    broken.extend_from_slice(&[7, 0, 0, 0, 0, 0, 0, 0, b'A', b'O', b'B', b'J']);
+    std::fs::write(&path, &broken).unwrap();
+
+    let mut container = Container::open(&path).unwrap();
+    assert_eq!(container.names(), &["x", "y"]);
+    let y: String = container.read_as("y").unwrap();
+    assert_eq!(y, "second");
+
+    // The next append overwrites the broken tail.
+    container.append("z", vec![4.0f64, 5.0].into()).unwrap();
+    let container = Container::open(&path).unwrap();
+    assert_eq!(container.names(), &["x", "y", "z"]);
+    let z: Vec<f64> = container.read_as("z").unwrap();
+    assert_eq!(z, vec![4.0, 5.0]);
+    let fresh = std::env::temp_dir().join("test_container_interrupted_fresh.bin");
+    let mut container = Container::create(&fresh).unwrap();
+    container.append("x", vec![1u8, 2, 3].into()).unwrap();
+    container.append("y", "second".to_string().into()).unwrap();
+    container.append("z", vec![4.0f64, 5.0].into()).unwrap();
+    assert_eq!(
+        std::fs::read(&fresh).unwrap(),
+        std::fs::read(&path).unwrap()
+    );
+
+    // A marker inside the broken tail that does not end a valid index is skipped.
+    let mut broken = complete;
+    broken.extend_from_slice(b"AOBJLISTAOBJ");
+    std::fs::write(&path, &broken).unwrap();
+    assert_eq!(Container::open(&path).unwrap().names(), &["x", "y"]);
+}
@@ -0,0 +1,73 @@
+use array_object::*;
+
+#[test]
+fn decimal_scale() {
+    let obj = ArrayObject::from(vec![15i64, -2])
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    let restored: Vec<f64> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![1.5, -0.2]);
+    let err = Vec::<i64>::try_from(obj.clone()).err().unwrap();
+    assert!(
+        err.to_string()
+            .starts_with("The data cannot be converted without loss")
+    );
+    let lossy = obj
+        .clone()
+        .cast(DataType::SignedInteger, CastOption::Lossy)
+        .unwrap();
+    assert_eq!(lossy.unit(), Some(Unit::Decimal(-1)));
+    let truncated = obj
+        .clone()
+        .cast(DataType::UnsignedInteger, CastOption::Lossy)
+        .unwrap();
+    assert_eq!(truncated.unit(), None);
+    assert_eq!(Vec::<u8>::try_from(truncated).unwrap(), vec![1, 0]);
+
+    let obj = ArrayObject::from(vec![150i64, -20])
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    let restored: Vec<i64> = obj.try_into().unwrap();
+    assert_eq!(restored, vec![15, -2]);
+    let obj = ArrayObject::from(vec![15i64, -2])
+        .with_unit(Unit::Decimal(2))
+        .unwrap();
+    let restored: Vec<i64> = obj.try_into().unwrap();
+    assert_eq!(restored, vec![1500, -200]);
+    let restored: i64 = ArrayObject::from(3i64)
+        .with_unit(Unit::Decimal(1))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(restored, 30);
+    let obj = ArrayObject::from(vec![1i64])
+        .with_unit(Unit::Decimal(40))
+        .unwrap();
+    assert!(Vec::<i64>::try_from(obj).is_err());
+}
+
+#[test]
+fn decimal_compute() {
+    let obj = ArrayObject::from(vec![15i64, -2, 7])
+        .with_unit(Unit::Decimal(-1))
+        .unwrap();
+    let sum = obj.sum(None).unwrap();
+    assert_eq!(sum.unit(), Some(Unit::Decimal(-1)));
+    assert_eq!(f64::try_from(sum).unwrap(), 2.0);
+    let max: f64 = obj.max(None).unwrap().try_into().unwrap();
+    assert_eq!(max, 1.5);
+    let mean: f64 = obj.mean(None).unwrap().try_into().unwrap();
+    assert!((mean - 2.0 / 3.0).abs() < 1e-12);
+
+    assert_eq!(format!("{obj}"), "[ 1.5, -0.2,  0.7]");
+    let obj = ArrayObject::from(vec![-5i64, 123456])
+        .with_unit(Unit::Decimal(-3))
+        .unwrap();
+    assert_eq!(format!("{obj}"), "[ -0.005, 123.456]");
+    let obj = ArrayObject::from(7i64).with_unit(Unit::Decimal(2)).unwrap();
+    assert_eq!(format!("{obj}"), "700");
+    let obj = ArrayObject::from(7i64)
+        .with_unit(Unit::Decimal(-50))
+        .unwrap();
+    assert_eq!(format!("{obj}"), "7e-50");
+}
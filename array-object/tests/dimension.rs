@@ -0,0 +1,71 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn large_dimension() {
+    let shape = vec![2; 17];
+    let original: Vec<i32> = (0..1 << 17).map(|x| x - 1000).collect();
+    let obj: ArrayObject = VecShape(original.clone(), shape.clone())
+        .try_into()
+        .unwrap();
+    assert_eq!(obj.shape().len(), 17);
+    let binary = obj.clone().pack();
+    assert_eq!(
+        DataType::describe_footer(&binary),
+        "Array of more than 15 dimensions"
+    );
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(unpacked, obj);
+    let VecShape::<i32>(restored, restored_shape) = unpacked.try_into().unwrap();
+    assert_eq!(restored, original);
+    assert_eq!(restored_shape, shape);
+    assert_eq!(
+        ArrayObject::unpack(obj.pack_as_it_is())
+            .unwrap()
+            .shape()
+            .len(),
+        17
+    );
+
+    let original = vec![Some(1.5f64), None, Some(0.0), Some(0.0)];
+    let obj: ArrayObject = VecShape(
+        original.clone(),
+        vec![1; 15].into_iter().chain([2, 2]).collect(),
+    )
+    .try_into()
+    .unwrap();
+    let VecShape::<Option<f64>>(restored, _) =
+        ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn fifteen_dimensions() {
+    let obj: ArrayObject = VecShape(vec![7u8, 9], vec![1; 14].into_iter().chain([2]).collect())
+        .try_into()
+        .unwrap();
+    let binary = obj.clone().pack_as_it_is();
+    // The dimension 15 still fits in the footer of the data type.
+    assert_eq!(binary, [vec![7, 9, 2], vec![1; 14], vec![0x4f]].concat());
+    assert_eq!(
+        DataType::describe_footer(&binary),
+        "15-dimensional fixed length unsigned integer"
+    );
+
+    let stacked = vec![obj.clone(), obj].try_concat().unwrap();
+    assert_eq!(stacked.shape().len(), 16);
+    assert_eq!(
+        ArrayObject::unpack(stacked.clone().pack()).unwrap(),
+        stacked
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_error() {
+    let err = ArrayObjectError::TooLargeDimension(16);
+    assert_eq!(
+        err.to_string(),
+        "The dimension of the array should be smaller than 16: found 16."
+    );
+}
@@ -0,0 +1,49 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn display() {
+    let obj: ArrayObject = 42u8.into();
+    assert_eq!(format!("{obj}"), "42");
+
+    let obj: ArrayObject = VecShape(vec![1i32, -20, 3, 4], vec![2, 2])
+        .try_into()
+        .unwrap();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(format!("{unpacked}"), "[[  1, -20],\n [  3,   4]]");
+
+    let obj: ArrayObject = VecShape(vec![0u8; 8], vec![2, 2, 2]).try_into().unwrap();
+    assert_eq!(
+        format!("{obj}"),
+        "[[[0, 0],\n  [0, 0]],\n\n [[0, 0],\n  [0, 0]]]"
+    );
+
+    let obj: ArrayObject = vec![Complex::new(1.5f64, -2.0), Complex::new(0.0, 0.25)].into();
+    assert_eq!(format!("{obj:.2}"), "[1.50-2.00i, 0.00+0.25i]");
+
+    let obj: ArrayObject = vec![0.1f32, 2.0].into();
+    assert_eq!(format!("{obj}"), "[0.1, 2.0]");
+
+    let obj: ArrayObject = vec!["a", "bc"].into();
+    assert_eq!(format!("{obj}"), r#"[ "a", "bc"]"#);
+
+    let obj: ArrayObject = Vec::<f64>::new().into();
+    assert_eq!(format!("{obj}"), "[]");
+}
+
+#[test]
+fn summarize() {
+    let obj: ArrayObject = VecShape((0..2000u32).collect(), vec![2, 1000])
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        format!("{obj}"),
+        "[[   0,    1,    2, ...,  997,  998,  999],\n [1000, 1001, 1002, ..., 1997, 1998, 1999]]"
+    );
+    assert_eq!(
+        format!("{}", obj.display().edge_items(1)),
+        "[[   0, ...,  999],\n [1000, ..., 1999]]"
+    );
+}
@@ -0,0 +1,55 @@
+use array_object::*;
+
+#[test]
+fn extension_layout() {
+    let obj: ArrayObject = vec![Some(1u8), None].into();
+    let binary = obj.clone().pack_as_it_is();
+    // Data, the footer of the 1-dimensional u8 array, the bitmap, and the footer of the extension.
+    assert_eq!(binary, vec![1, 0, 2, 0x41, 0b01, 4, 0, 2, 1, 0xe0]);
+    assert_eq!(ArrayObject::unpack(binary).unwrap(), obj);
+}
+
+#[test]
+fn unsupported_extension() {
+    let binary = vec![0, 9, 0xe0];
+    assert_eq!(DataType::describe_footer(&binary), "Extension 9");
+    let err = ArrayObject::unpack(binary).err().unwrap();
+    assert!(err.to_string().starts_with("Unsupported extension 9."));
+
+    let inner: ArrayObject = vec![1.5f64, 2.5].into();
+    // The metadata [1, 2] of an unknown extension is skipped by its length.
+    let binary = [inner.pack(), vec![2, 1, 2, 200, 0xe0]].concat();
+    assert_eq!(DataType::describe_footer(&binary), "Extension 200");
+    let err = ArrayObject::unpack(binary).err().unwrap();
+    assert!(err.to_string().starts_with("Unsupported extension 200."));
+
+    let binary = vec![0, 0, 0xe1];
+    assert_eq!(DataType::describe_footer(&binary), "Extension of version 1");
+    let err = ArrayObject::unpack(binary).err().unwrap();
+    assert!(err.to_string().starts_with("Unsupported version 1"));
+}
+
+#[test]
+fn broken_extension() {
+    assert!(ArrayObject::unpack(vec![0xe0]).is_err());
+    assert!(ArrayObject::unpack(vec![1, 0xe0]).is_err());
+    assert!(ArrayObject::unpack(vec![9, 1, 0xe0]).is_err());
+    assert_eq!(DataType::describe_footer(&vec![0xe0]), "Broken extension");
+}
+
+#[test]
+fn nested_extension() {
+    let obj: ArrayObject = vec![1u8, 2].into();
+    // The footer of a checksum extension with the checksum 0, which is not verified.
+    let footer = [0, 0, 2, 4, 0xe0];
+    let binary = [obj.clone().pack(), footer.to_vec()].concat();
+    assert_eq!(
+        ArrayObject::unpack_with(binary.clone(), UnpackOption::SkipVerification).unwrap(),
+        obj
+    );
+    // Each extension appears at most once in the nesting.
+    let binary = [binary, footer.to_vec()].concat();
+    assert!(ArrayObject::unpack_with(binary, UnpackOption::SkipVerification).is_err());
+    let binary = [obj.pack(), footer.repeat(1_000_000)].concat();
+    assert!(ArrayObject::unpack_with(binary, UnpackOption::SkipVerification).is_err());
+}
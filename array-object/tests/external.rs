@@ -1,11 +1,13 @@
-#[cfg(any(feature = "ndarray_15", feature = "ndarray_16"))]
+#[cfg(any(feature = "ndarray_15", feature = "ndarray_16", feature = "ndarray_17"))]
 mod test_ndarray {
     use array_object::*;
-    use ndarray::{Array1, Array2};
+    use ndarray::{ArcArray, Array1, Array2, Array3, ArrayD, CowArray, Ix2, IxDyn};
     #[cfg(feature = "ndarray_15")]
     use ndarray_15 as ndarray;
     #[cfg(feature = "ndarray_16")]
     use ndarray_16 as ndarray;
+    #[cfg(feature = "ndarray_17")]
+    use ndarray_17 as ndarray;
     #[test]
     fn ndarray_integer() {
         let v: Vec<_> = (-128..128).map(|i| i as i32).collect();
@@ -27,12 +29,49 @@ mod test_ndarray {
         let restored: Array1<f64> = unpacked.try_into().unwrap();
         assert_eq!(original, restored);
     }
+    #[test]
+    fn ndarray_layout() {
+        let original = Array2::from_shape_vec((2, 3), vec![1u16, 2, 3, 4, 5, 6]).unwrap();
+        let transposed = original.t();
+        let obj: ArrayObject = transposed.try_into().unwrap();
+        let restored: ArcArray<u16, Ix2> = obj.clone().try_into().unwrap();
+        assert_eq!(restored, transposed);
+        let restored: CowArray<u16, IxDyn> = obj.clone().try_into().unwrap();
+        assert_eq!(restored.shape(), &[3, 2]);
+        assert!(Array3::<u16>::try_from(obj).is_err());
+
+        let original = ArrayD::from_shape_vec(
+            IxDyn(&[2, 1, 1, 1, 1, 1, 2]),
+            (0..4).map(|i| i.to_string()).collect(),
+        )
+        .unwrap();
+        let obj: ArrayObject = original.clone().try_into().unwrap();
+        let restored: ArrayD<String> = obj.try_into().unwrap();
+        assert_eq!(original, restored);
+    }
+    #[test]
+    fn ndarray_view() {
+        let original =
+            Array2::from_shape_vec((2, 3), (0..6).map(|i| i as f64 + 0.1).collect()).unwrap();
+        let obj: ArrayObject = original.try_into().unwrap();
+        let mut unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+        let view = unpacked.as_array_view::<f64>().unwrap();
+        assert_eq!(view.shape(), &[2, 3]);
+        assert_eq!(view[[1, 2]], 5.1);
+        unpacked.as_array_view_mut::<f64>().unwrap()[[0, 0]] = -1.0;
+        let restored: Array2<f64> = unpacked.clone().try_into().unwrap();
+        assert_eq!(restored[[0, 0]], -1.0);
+        assert!(unpacked.as_array_view::<f32>().is_err());
+
+        let obj: ArrayObject = vec![1i32, 2].into();
+        assert!(obj.as_array_view::<u32>().is_err());
+    }
 }
 
 #[cfg(feature = "nalgebra")]
 mod test_nalgebra {
     use array_object::*;
-    use nalgebra::DMatrix;
+    use nalgebra::{Const, DMatrix, Dyn, Matrix, OMatrix, RowDVector, SMatrix, Vector3};
     #[test]
     fn nalgebra_integer() {
         let v: Vec<_> = (-128..128).map(|i| i as i32).collect();
@@ -45,4 +84,402 @@ mod test_nalgebra {
         let restored: DMatrix<i32> = unpacked.try_into().unwrap();
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn nalgebra_static() {
+        let original = SMatrix::<f64, 2, 3>::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let obj: ArrayObject = original.try_into().unwrap();
+        let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+        let restored: SMatrix<f64, 2, 3> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let partial: OMatrix<f64, Dyn, Const<3>> = unpacked.clone().try_into().unwrap();
+        assert_eq!(partial[(1, 2)], 6.0);
+        assert!(SMatrix::<f64, 3, 3>::try_from(unpacked.clone()).is_err());
+        assert!(Matrix::<f64, Const<3>, Dyn, _>::try_from(unpacked).is_err());
+    }
+    #[test]
+    fn nalgebra_vector() {
+        let obj: ArrayObject = vec![1u8, 2, 3].into();
+        let column: Vector3<u8> = obj.clone().try_into().unwrap();
+        assert_eq!(column, Vector3::new(1, 2, 3));
+        let row: RowDVector<u8> = obj.clone().try_into().unwrap();
+        assert_eq!(row.ncols(), 3);
+        let matrix: DMatrix<u8> = obj.clone().try_into().unwrap();
+        assert_eq!(matrix.shape(), (3, 1));
+        assert!(nalgebra::Vector4::<u8>::try_from(obj).is_err());
+    }
+}
+
+#[cfg(feature = "nalgebra_sparse")]
+mod test_nalgebra_sparse {
+    use array_object::adaptor::VecShape;
+    use array_object::*;
+    use nalgebra_sparse::{CooMatrix, CsrMatrix};
+    #[test]
+    fn nalgebra_sparse() {
+        let mut coo = CooMatrix::new(3, 4);
+        coo.push(0, 1, 1.5f64);
+        coo.push(2, 3, -2.0);
+        let original = CsrMatrix::from(&coo);
+        let obj: ArrayObject = (&original).try_into().unwrap();
+        let VecShape::<f64>(data, shape) = obj.clone().try_into().unwrap();
+        assert_eq!(shape, vec![3, 4]);
+        assert_eq!(data[1], 1.5);
+        assert_eq!(data[11], -2.0);
+        let restored: CsrMatrix<f64> = obj.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let restored: CooMatrix<f64> = obj.try_into().unwrap();
+        assert_eq!(restored.nnz(), 2);
+
+        let obj: ArrayObject = vec![1i32, 0, 2].into();
+        assert!(CsrMatrix::<i32>::try_from(obj).is_err());
+
+        // The duplicate entries are added up in the same way as the conversion into CSR.
+        let mut coo = CooMatrix::new(2, 2);
+        coo.push(1, 0, 1i32);
+        coo.push(0, 1, 4);
+        coo.push(1, 0, -3);
+        let obj: ArrayObject = (&coo).try_into().unwrap();
+        let VecShape::<i32>(data, _) = obj.clone().try_into().unwrap();
+        assert_eq!(data, vec![0, 4, -2, 0]);
+        let restored: CsrMatrix<i32> = obj.try_into().unwrap();
+        assert_eq!(restored, CsrMatrix::from(&coo));
+
+        let mut coo = CooMatrix::new(1 << 40, 1 << 40);
+        coo.push(0, 0, 1.0f32);
+        assert!(ArrayObject::try_from(&coo).is_err());
+    }
+}
+
+#[cfg(feature = "sprs")]
+mod test_sprs {
+    use array_object::adaptor::VecShape;
+    use array_object::*;
+    use sprs::{CsMat, TriMat};
+    #[test]
+    fn sprs_matrix() {
+        let mut tri = TriMat::new((50, 40));
+        tri.add_triplet(0, 39, 2i64);
+        tri.add_triplet(49, 0, -7);
+        let original: CsMat<i64> = tri.to_csr();
+        let obj: ArrayObject = (&original).try_into().unwrap();
+        let binary = obj.pack();
+        assert!(binary.len() < 50);
+        let unpacked = ArrayObject::unpack(binary).unwrap();
+        let restored: CsMat<i64> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let restored: TriMat<i64> = unpacked.try_into().unwrap();
+        assert_eq!(restored.nnz(), 2);
+
+        // The duplicate entries are added up in the same way as `to_csr()`.
+        let mut tri = TriMat::new((2, 3));
+        tri.add_triplet(0, 0, 1.0f64);
+        tri.add_triplet(1, 2, 0.5);
+        tri.add_triplet(0, 0, 2.0);
+        let obj: ArrayObject = (&tri).try_into().unwrap();
+        let VecShape::<f64>(data, _) = obj.try_into().unwrap();
+        assert_eq!(data, vec![3.0, 0.0, 0.0, 0.0, 0.0, 0.5]);
+        let restored: CsMat<f64> = ArrayObject::try_from(&tri).unwrap().try_into().unwrap();
+        assert_eq!(restored, tri.to_csr());
+        let mut tri = TriMat::new((1, 1));
+        tri.add_triplet(0, 0, 200u8);
+        tri.add_triplet(0, 0, 100);
+        assert!(ArrayObject::try_from(&tri).is_err());
+
+        // A huge matrix with few elements is an error rather than an abort.
+        let mut tri = TriMat::new((1 << 40, 1 << 40));
+        tri.add_triplet(0, 0, 1.0f64);
+        assert!(ArrayObject::try_from(&tri).is_err());
+    }
+}
+
+#[cfg(feature = "bytes")]
+mod test_bytes {
+    use array_object::*;
+    use bytes::Bytes;
+    #[test]
+    fn bytes_blob() {
+        let original = vec![Bytes::from_static(b"\xff\x00"), Bytes::new()];
+        let obj: ArrayObject = original.clone().into();
+        let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+        let restored: Vec<Bytes> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let restored: Vec<Vec<u8>> = unpacked.try_into().unwrap();
+        assert_eq!(restored, vec![vec![0xFF, 0x00], vec![]]);
+
+        let obj: ArrayObject = Bytes::from_static(b"blob").into();
+        let restored: Bytes = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(restored, Bytes::from_static(b"blob"));
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod test_chrono {
+    use array_object::*;
+    use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+    #[test]
+    fn chrono_time() {
+        let original: Vec<DateTime<Utc>> = vec![
+            DateTime::from_timestamp(1_700_000_000, 123).unwrap(),
+            DateTime::from_timestamp(-1, 999_999_999).unwrap(),
+        ];
+        let obj: ArrayObject = original.clone().into();
+        let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+        let restored: Vec<DateTime<Utc>> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        assert!(Vec::<TimeDelta>::try_from(unpacked).is_err());
+
+        let original = vec![
+            NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        ];
+        let obj: ArrayObject = original.clone().into();
+        assert_eq!(obj.unit(), Some(Unit::Date));
+        let restored: Vec<NaiveDate> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(original, restored);
+
+        let original = TimeDelta::new(-5, 10).unwrap();
+        let obj: ArrayObject = original.into();
+        let restored: TimeDelta = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(original, restored);
+    }
+}
+
+#[cfg(feature = "time")]
+mod test_time {
+    use array_object::*;
+    use time::{Date, Duration, Month, OffsetDateTime};
+    #[test]
+    fn time_types() {
+        let original = vec![
+            OffsetDateTime::UNIX_EPOCH,
+            OffsetDateTime::from_unix_timestamp_nanos(-1).unwrap(),
+        ];
+        let obj: ArrayObject = original.clone().into();
+        let restored: Vec<OffsetDateTime> =
+            ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(original, restored);
+
+        let original = vec![Date::from_calendar_date(1970, Month::January, 2).unwrap()];
+        let obj: ArrayObject = original.clone().into();
+        let restored: Vec<i32> = obj.clone().try_into().unwrap();
+        assert_eq!(restored, vec![1]);
+        let restored: Vec<Date> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(original, restored);
+
+        let original = Duration::new(-3, -5);
+        let obj: ArrayObject = original.into();
+        let restored: Duration = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(original, restored);
+    }
+}
+
+#[cfg(feature = "num_bigint")]
+mod test_num_bigint {
+    use array_object::*;
+    use num_bigint::{BigInt, BigUint};
+    #[test]
+    fn bigint() {
+        let big = BigUint::from(3u32).pow(100);
+        let huge = BigUint::from(7u32).pow(400);
+        let original = vec![
+            BigUint::from(1u32),
+            big.clone(),
+            huge.clone(),
+            BigUint::from(0u32),
+        ];
+        let obj: ArrayObject = original.clone().into();
+        assert_eq!(obj.bits(), Some(2048));
+        let unpacked = ArrayObject::unpack(obj.clone().pack()).unwrap();
+        assert!(unpacked.semantically_eq(&obj));
+        let restored: Vec<BigUint> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let restored: Vec<BigUint> = ArrayObject::unpack(obj.clone().pack_as_it_is())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(original, restored);
+        assert!(unpacked.sum(None).is_err());
+        assert!(format!("{}", unpacked).contains(&big.to_string()));
+
+        let err = Vec::<u128>::try_from(unpacked).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The integer type is imcompatible with the data. Required: u2048"
+        );
+
+        let original = vec![
+            -BigInt::from(big.clone()),
+            BigInt::from(5),
+            BigInt::from(-1),
+        ];
+        let obj: ArrayObject = original.clone().into();
+        let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+        assert!(format!("{}", unpacked).starts_with(&format!("[-{big}")));
+        let restored: Vec<BigInt> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        assert!(Vec::<BigUint>::try_from(unpacked).is_err());
+
+        let obj = ArrayObject::from(original.clone())
+            .with_unit(Unit::Duration(TimeUnit::Second))
+            .unwrap();
+        let unpacked = ArrayObject::unpack(obj.clone().pack()).unwrap();
+        assert!(unpacked.semantically_eq(&obj));
+        assert_eq!(unpacked.unit(), Some(Unit::Duration(TimeUnit::Second)));
+        let restored: Vec<BigInt> = unpacked.try_into().unwrap();
+        assert_eq!(original, restored);
+
+        let obj: ArrayObject = vec![-3i16, 4].into();
+        let restored: Vec<BigInt> = obj.try_into().unwrap();
+        assert_eq!(restored, vec![BigInt::from(-3), BigInt::from(4)]);
+        let obj: ArrayObject = vec![BigUint::from(u64::MAX), BigUint::from(1u32)].into();
+        let restored: Vec<u64> = obj.try_into().unwrap();
+        assert_eq!(restored, vec![u64::MAX, 1]);
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+mod test_rust_decimal {
+    use array_object::*;
+    use rust_decimal::Decimal;
+    #[test]
+    fn decimal() {
+        let original = vec![
+            Decimal::new(12345, 2),
+            Decimal::new(-5, 0),
+            Decimal::new(1, 4),
+        ];
+        let obj = ArrayObject::try_from(original.clone()).unwrap();
+        assert_eq!(obj.unit(), Some(Unit::Decimal(-4)));
+        let unpacked = ArrayObject::unpack(obj.clone().pack()).unwrap();
+        assert_eq!(unpacked, obj);
+        let restored: Vec<Decimal> = unpacked.clone().try_into().unwrap();
+        assert_eq!(original, restored);
+        let restored: Vec<Decimal> = ArrayObject::unpack(obj.pack_as_it_is())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(original, restored);
+        let scaled: Vec<f64> = unpacked.try_into().unwrap();
+        assert_eq!(scaled, vec![123.45, -5.0, 0.0001]);
+
+        let obj = ArrayObject::from(vec![15i64, -2])
+            .with_unit(Unit::Decimal(3))
+            .unwrap();
+        let restored: Vec<Decimal> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+        assert_eq!(
+            restored,
+            vec![Decimal::new(15000, 0), Decimal::new(-2000, 0)]
+        );
+        let restored: Decimal = ArrayObject::from(7u8).try_into().unwrap();
+        assert_eq!(restored, Decimal::new(7, 0));
+
+        let obj = ArrayObject::from(vec![1i64])
+            .with_unit(Unit::Decimal(-40))
+            .unwrap();
+        let err = Vec::<Decimal>::try_from(obj).err().unwrap();
+        assert!(
+            err.to_string()
+                .starts_with("The data cannot be converted without loss")
+        );
+        let obj = ArrayObject::from(vec![1i64])
+            .with_unit(Unit::Duration(TimeUnit::Second))
+            .unwrap();
+        let err = Vec::<Decimal>::try_from(obj).err().unwrap();
+        assert!(err.to_string().starts_with("The wrong unit"));
+    }
+}
+
+#[cfg(feature = "memmap2")]
+mod test_memmap2 {
+    use array_object::*;
+    use num_complex::Complex;
+    #[test]
+    fn mmap() {
+        let original: Vec<f64> = (0..1000).map(|x| x as f64 * 0.1).collect();
+        let obj: ArrayObject = original.clone().into();
+        let path = std::env::temp_dir().join("test_mmap_real.bin");
+        std::fs::write(&path, obj.pack_as_it_is()).unwrap();
+        let mapped = ArrayObject::open_mmap(&path).unwrap();
+        assert_eq!(mapped.shape().unwrap(), vec![1000]);
+        assert_eq!(mapped.datatype().unwrap(), DataType::Real);
+        assert_eq!(mapped.as_slice::<f64>().unwrap(), original.as_slice());
+        assert!(mapped.as_slice::<f32>().is_err());
+        assert!(mapped.as_slice::<u64>().is_err());
+
+        let original = vec![Complex::new(1.0f32, -1.0), Complex::new(0.5, 2.0)];
+        let obj: ArrayObject = original.clone().into();
+        let path = std::env::temp_dir().join("test_mmap_complex.bin");
+        std::fs::write(&path, obj.pack_as_it_is()).unwrap();
+        let mapped = ArrayObject::open_mmap(&path).unwrap();
+        assert_eq!(
+            mapped.as_slice::<Complex<f32>>().unwrap(),
+            original.as_slice()
+        );
+
+        let original = vec![-1i64, 1 << 40, 3];
+        let obj: ArrayObject = original.clone().into();
+        let path = std::env::temp_dir().join("test_mmap_signed.bin");
+        std::fs::write(&path, obj.clone().pack()).unwrap();
+        let mapped = ArrayObject::open_mmap(&path).unwrap();
+        assert!(mapped.as_slice::<u64>().is_err());
+        assert_eq!(mapped.shape().unwrap(), vec![3]);
+        assert_eq!(mapped.object().unwrap(), &obj);
+        let restored: Vec<i64> = mapped.object().unwrap().clone().try_into().unwrap();
+        assert_eq!(restored, original);
+
+        assert!(
+            ArrayObject::open_mmap(std::env::temp_dir().join("test_mmap_missing.bin")).is_err()
+        );
+    }
+    #[cfg(any(feature = "ndarray_15", feature = "ndarray_16", feature = "ndarray_17"))]
+    #[test]
+    fn mmap_ndarray() {
+        let obj: ArrayObject = adaptor::VecShape((0..12u32).collect(), vec![3, 4])
+            .try_into()
+            .unwrap();
+        let path = std::env::temp_dir().join("test_mmap_ndarray.bin");
+        std::fs::write(&path, obj.pack_as_it_is()).unwrap();
+        let mapped = ArrayObject::open_mmap(&path).unwrap();
+        let view = mapped.as_array_view::<u32>().unwrap();
+        assert_eq!(view.shape(), &[3, 4]);
+        assert_eq!(view[[2, 1]], 9);
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod test_tokio {
+    use array_object::*;
+    use std::io::Cursor;
+    #[test]
+    fn tokio() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let original: Vec<f64> = (0..10000).map(|x| x as f64 / 7.0).collect();
+            let obj: ArrayObject = original.clone().into();
+            let mut cursor = Cursor::new(b"header".to_vec());
+            cursor.set_position(6);
+            obj.clone().pack_to_async(&mut cursor).await.unwrap();
+            cursor.set_position(6);
+            let unpacked = ArrayObject::unpack_from_async(&mut cursor).await.unwrap();
+            assert_eq!(unpacked, obj);
+
+            let binary = obj.clone().pack_blocking().await.unwrap();
+            assert_eq!(binary, obj.clone().pack());
+            let restored: Vec<f64> = ArrayObject::unpack_blocking(binary)
+                .await
+                .unwrap()
+                .try_into()
+                .unwrap();
+            assert_eq!(restored, original);
+
+            let path = std::env::temp_dir().join("test_tokio.bin");
+            obj.clone().save_async(&path).await.unwrap();
+            assert_eq!(ArrayObject::load_async(&path).await.unwrap(), obj);
+            let missing = std::env::temp_dir().join("test_tokio_missing.bin");
+            assert!(ArrayObject::load_async(missing).await.is_err());
+        });
+    }
 }
@@ -0,0 +1,80 @@
+use array_object::*;
+use std::error::Error;
+
+#[test]
+fn save_and_load() {
+    let dir = std::env::temp_dir();
+    let obj: ArrayObject = vec![Some(1i32), None, Some(-3)].into();
+    obj.clone().save(dir.join("test_file_save.bin")).unwrap();
+    let restored = ArrayObject::load(dir.join("test_file_save.bin")).unwrap();
+    assert!(restored.semantically_eq(&obj));
+
+    let path = dir.join("test_file_save_as.bin");
+    ArrayObject::save_as(vec!["a".to_string(), "b".to_string()], &path).unwrap();
+    let restored: Vec<String> = ArrayObject::load_as(&path).unwrap();
+    assert_eq!(restored, vec!["a", "b"]);
+    assert!(ArrayObject::load_as::<Vec<f64>>(&path).is_err());
+}
+
+#[test]
+fn macros_with_expressions() {
+    let names = ["first", "second"];
+    for (i, name) in names.iter().enumerate() {
+        let path = std::env::temp_dir().join(format!("test_file_{name}.bin"));
+        export_obj!(&path, vec![i as u64; 3]);
+        let restored: Vec<u64> = import_obj!(&path);
+        assert_eq!(restored, vec![i as u64; 3]);
+        try_export_obj!(path.to_str().unwrap(), 1.5f32).unwrap();
+        let restored: f32 = try_import_obj!(path.as_path()).unwrap();
+        assert_eq!(restored, 1.5);
+    }
+}
+
+#[test]
+fn file_errors() -> Result<(), ArrayObjectError> {
+    let path = std::env::temp_dir().join("test_file_missing.bin");
+    let err = try_import_obj!(&path).map(|_: Vec<u8>| ()).err().unwrap();
+    assert!(err.to_string().starts_with("I/O error"));
+    assert!(err.source().is_some());
+
+    let path = std::env::temp_dir().join("test_file_broken.bin");
+    std::fs::write(&path, [0xe0])?;
+    assert!(ArrayObject::load(&path).is_err());
+
+    let path = std::env::temp_dir().join("test_file_result.bin");
+    try_export_obj!(&path, vec![1u8, 2])?;
+    let restored: Vec<u16> = try_import_obj!(&path)?;
+    assert_eq!(restored, vec![1, 2]);
+    Ok(())
+}
+
+#[test]
+fn atomic_save() {
+    let dir = std::env::temp_dir().join("test_file_atomic");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir(&dir).unwrap();
+    let path = dir.join("data.bin");
+    let obj: ArrayObject = vec![Some(f64::NAN), None, Some(2.5)].into();
+    let option = SaveOption {
+        pack: PackOption {
+            checksum: true,
+            ..Default::default()
+        },
+        verify: true,
+    };
+    obj.clone().save_with(&path, option).unwrap();
+    assert!(ArrayObject::load(&path).unwrap().semantically_eq(&obj));
+
+    // The target is a directory, so the rename fails and the original is kept.
+    std::fs::create_dir(dir.join("target")).unwrap();
+    assert!(obj.clone().save(dir.join("target")).is_err());
+    assert!(dir.join("target").is_dir());
+    assert!(obj.save(dir.join("missing").join("data.bin")).is_err());
+
+    let names: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|x| x.unwrap().file_name())
+        .collect();
+    assert_eq!(names.len(), 2);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,50 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+use num_complex::Complex;
+
+#[test]
+fn from_iter() {
+    let obj: ArrayObject = (-128..128i32).collect();
+    let original: Vec<i32> = (-128..128).collect();
+    assert_eq!(obj, original.into());
+
+    let obj: ArrayObject = ["a", "b", "c"].into_iter().collect();
+    let restored: Vec<String> = obj.try_into().unwrap();
+    assert_eq!(restored, vec!["a", "b", "c"]);
+
+    let obj: ArrayObject = (0..4).map(|i| Complex::new(i as f32, 1.0)).collect();
+    assert_eq!(obj.shape(), vec![4]);
+
+    let obj: ArrayObject = [Some(1u8), None].into_iter().collect();
+    assert_eq!(obj.null_count(), 1);
+    let obj: ArrayObject = [vec![0xffu8], vec![]].into_iter().collect();
+    assert_eq!(obj.datatype(), DataType::Bytes);
+
+    let obj = ArrayObject::from_iter_shape((0..6u8).map(|i| i.to_string()), vec![3, 2]).unwrap();
+    let VecShape::<String>(restored, shape) = obj.try_into().unwrap();
+    assert_eq!(restored[5], "5");
+    assert_eq!(shape, vec![3, 2]);
+
+    assert!(ArrayObject::from_iter_shape(0..5u8, vec![3, 2]).is_err());
+}
+
+#[test]
+fn iter() {
+    let original: Vec<_> = (0..300).map(|i| i as u16).collect();
+    let obj: ArrayObject = original.clone().into();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let restored: Vec<u16> = unpacked.iter::<u16>().map(|x| x.unwrap()).collect();
+    assert_eq!(original, restored);
+    assert_eq!(unpacked.iter::<f64>().len(), 300);
+    assert!(unpacked.iter::<u8>().any(|x| x.is_err()));
+
+    let original = vec!["test".to_string(), "".to_string(), "テスト".to_string()];
+    let obj: ArrayObject = original.clone().into();
+    let restored: Result<Vec<String>, _> = obj.iter::<String>().collect();
+    assert_eq!(original, restored.unwrap());
+
+    let obj: ArrayObject = vec![Complex::new(1.0f64, -1.0)].into();
+    let restored: Vec<Complex<f64>> = obj.iter().map(|x| x.unwrap()).collect();
+    assert_eq!(restored, vec![Complex::new(1.0, -1.0)]);
+}
@@ -0,0 +1,69 @@
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn nullable_integer() {
+    let original = vec![Some(-1i32), None, Some(70000), None];
+    let obj: ArrayObject = original.clone().into();
+    assert!(obj.is_nullable());
+    assert_eq!(obj.null_count(), 2);
+    let binary = obj.clone().pack();
+    assert_eq!(DataType::describe_footer(&binary), "Nullable array");
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(unpacked.validity(), Some(vec![true, false, true, false]));
+    let restored: Vec<Option<i64>> = unpacked.clone().try_into().unwrap();
+    assert_eq!(restored, vec![Some(-1), None, Some(70000), None]);
+    assert!(Vec::<i32>::try_from(unpacked.clone()).is_err());
+    assert!(unpacked.sum(None).is_err());
+    assert_eq!(format!("{}", unpacked), "[   -1,  None, 70000,  None]");
+
+    let unpacked = ArrayObject::unpack(obj.pack_as_it_is()).unwrap();
+    let restored: Vec<Option<i32>> = unpacked.try_into().unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn nullable_string_and_float() {
+    let original = vec![Some("a".to_string()), None, Some("".to_string())];
+    let obj: ArrayObject = VecShape(original.clone(), vec![3, 1]).try_into().unwrap();
+    let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+    let VecShape::<Option<String>>(restored, shape) = unpacked.try_into().unwrap();
+    assert_eq!(restored, original);
+    assert_eq!(shape, vec![3, 1]);
+
+    let obj: ArrayObject = vec![1.5f64, f64::NAN, 2.5].into();
+    let obj = obj.with_validity(vec![true, false, true]).unwrap();
+    let restored: Vec<Option<f64>> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+    assert_eq!(restored, vec![Some(1.5), None, Some(2.5)]);
+
+    let obj: ArrayObject = vec![1u8, 2].into();
+    assert!(obj.with_validity(vec![true]).is_err());
+}
+
+#[test]
+fn mostly_valid() {
+    let mut original: Vec<Option<u16>> = (0..10000).map(|i| Some(i as u16)).collect();
+    original[5000] = None;
+    let obj: ArrayObject = original.clone().into();
+    let dense: ArrayObject = (0..10000).map(|i| i as u16).collect();
+    assert!(obj.clone().pack().len() < dense.pack().len() + 16);
+    let restored: Vec<Option<u16>> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn nullable_concat_and_compare() {
+    let a: ArrayObject = vec![Some(1u8), None].into();
+    let b: ArrayObject = vec![3u8, 4].into();
+    let obj = vec![a.clone(), b.clone()].stack(0).unwrap();
+    assert_eq!(obj.validity(), Some(vec![true, false, true, true]));
+    let objs = obj.unstack(0).unwrap();
+    assert_eq!(objs[0], a);
+    assert_eq!(objs[1].validity(), Some(vec![true, true]));
+
+    let c: ArrayObject = vec![1u8, 0].into();
+    assert!(!a.semantically_eq(&c));
+    assert_eq!(a.diff(&c, 0.0, 0.0, 10).indices, vec![vec![1]]);
+    let d: ArrayObject = vec![Some(1u16), None].into();
+    assert!(a.semantically_eq(&d));
+}
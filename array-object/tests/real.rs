@@ -98,4 +98,30 @@ fn array() {
     let unpacked = ArrayObject::unpack(binary).unwrap();
     let restored: [f64; 128] = unpacked.try_into().unwrap();
     assert_eq!(original, restored);
-}
\ No newline at end of file
+}
+
+#[test]
+fn from_integer() {
+    let original: Vec<u32> = (0..256).map(|i| i * 1000).collect();
+    let obj: ArrayObject = original.clone().into();
+    let binary = obj.pack();
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let restored: Vec<f64> = unpacked.try_into().unwrap();
+    assert_eq!(original.iter().map(|&x| x as f64).collect::<Vec<_>>(), restored);
+
+    let obj: ArrayObject = (-3i8).into();
+    let restored: f32 = obj.try_into().unwrap();
+    assert_eq!(restored, -3.0);
+
+    // Integers are promoted into f64 first, so restoring them into f32 is a down conversion allowed by the feature.
+    let obj: ArrayObject = vec![16777217u32].into();
+    #[cfg(not(feature = "allow_float_down_convert"))]
+    assert!(TryInto::<Vec<f32>>::try_into(obj.clone()).is_err());
+    #[cfg(feature = "allow_float_down_convert")]
+    assert_eq!(
+        TryInto::<Vec<f32>>::try_into(obj.clone()).unwrap(),
+        vec![16777216.0]
+    );
+    let restored: Vec<f64> = obj.try_into().unwrap();
+    assert_eq!(restored, vec![16777217.0]);
+}
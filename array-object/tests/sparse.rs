@@ -0,0 +1,116 @@
+#![allow(clippy::unusual_byte_groupings)]
+
+use array_object::adaptor::VecShape;
+use array_object::*;
+
+#[test]
+fn sparse_matrix() {
+    let mut original = vec![0f64; 100 * 200];
+    original[3] = 1.5;
+    original[250] = -0.1;
+    original[100 * 200 - 1] = 1e300;
+    let obj: ArrayObject = VecShape(original.clone(), vec![100, 200])
+        .try_into()
+        .unwrap();
+    let binary = obj.pack();
+    assert!(binary.len() < 200);
+    assert_eq!(binary.last().unwrap() & 0b111_0_0000, 0b111_0_0000);
+    assert_eq!(DataType::describe_footer(&binary), "Sparse array");
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    let VecShape::<f64>(restored, shape) = unpacked.try_into().unwrap();
+    assert_eq!(restored, original);
+    assert_eq!(shape, vec![100, 200]);
+}
+
+#[test]
+fn sparse_tensor() {
+    let mut original = vec![0i32; 10 * 20 * 30];
+    original[7] = -70000;
+    original[4321] = 12;
+    let obj: ArrayObject = VecShape(original.clone(), vec![10, 20, 30])
+        .try_into()
+        .unwrap();
+    let unpacked = ArrayObject::unpack(obj.pack()).unwrap();
+    let restored: VecShape<i32> = unpacked.try_into().unwrap();
+    assert_eq!(restored.0, original);
+
+    let original = vec![0u8; 1000];
+    let obj: ArrayObject = original.clone().into();
+    let binary = obj.pack();
+    assert!(binary.len() < 20);
+    let restored: Vec<u8> = ArrayObject::unpack(binary).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn dense_is_kept() {
+    let original: Vec<_> = (0..100).map(|i| i as u8 % 2).collect();
+    let obj: ArrayObject = original.into();
+    let binary = obj.pack();
+    assert_ne!(binary.last().unwrap() & 0b111_0_0000, 0b111_0_0000);
+
+    let original = vec![0f32, 1.0, 0.0, 2.0, 3.0];
+    let obj: ArrayObject = original.clone().into();
+    let restored: Vec<f32> = ArrayObject::unpack(obj.pack()).unwrap().try_into().unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn broken_sparse() {
+    let mut original = vec![0u16; 400];
+    original[10] = 1000;
+    let obj: ArrayObject = VecShape(original, vec![20, 20]).try_into().unwrap();
+    let binary = obj.pack();
+    let n = binary.len();
+
+    let mut broken = binary.clone();
+    broken[n - 2] = 100;
+    assert!(ArrayObject::unpack(broken).is_err());
+
+    let mut broken = binary.clone();
+    broken[n - 4] = 9;
+    assert!(ArrayObject::unpack(broken).is_err());
+
+    assert!(ArrayObject::unpack(binary[n - 3..].to_vec()).is_err());
+}
+
+/// Packs the values and the indices with the footer of the sparse extension.
+fn sparse(values: ArrayObject, indices: Vec<u64>, metadata: &[u64]) -> Vec<u8> {
+    let mut data = values.pack_as_it_is();
+    let values_len = data.len() as u64;
+    data.append(&mut ArrayObject::from(indices).pack_as_it_is());
+    let mut varint = vec![];
+    for mut x in [&[metadata[0], values_len], &metadata[1..]].concat() {
+        while x >= 0x80 {
+            varint.push(x as u8 | 0x80);
+            x >>= 7;
+        }
+        varint.push(x as u8);
+    }
+    let mut footer = [vec![0xe0, 0, varint.len() as u8], varint].concat();
+    footer.reverse();
+    data.append(&mut footer);
+    data
+}
+
+#[test]
+fn implausible_sparse() {
+    let binary = sparse(vec![7u8].into(), vec![0, 1], &[0, 2, 2]);
+    let restored: VecShape<u8> = ArrayObject::unpack(binary).unwrap().try_into().unwrap();
+    assert_eq!(restored.0, vec![0, 7, 0, 0]);
+
+    let values: ArrayObject = vec![vec![1u8]].into();
+    assert!(ArrayObject::unpack(sparse(values, vec![0, 1], &[0, 2, 2])).is_err());
+    let values: ArrayObject = vec!["a"].into();
+    assert!(ArrayObject::unpack(sparse(values, vec![0, 1], &[0, 2, 2])).is_err());
+
+    // The number of elements overflows.
+    let binary = sparse(vec![7u8].into(), vec![0, 1], &[0, u64::MAX, 2]);
+    assert!(ArrayObject::unpack(binary).is_err());
+    // The dense array cannot be allocated.
+    let binary = sparse(vec![7u8].into(), vec![0, 1], &[0, 1 << 31, 1 << 31]);
+    assert!(ArrayObject::unpack(binary).is_err());
+    // More values than elements.
+    let binary = sparse(vec![7u8].into(), vec![0, 0], &[1, u64::MAX, 0]);
+    assert!(ArrayObject::unpack(binary).is_err());
+}
@@ -71,3 +71,53 @@ fn array() {
     let restored: [String; 3] = unpacked.try_into().unwrap();
     assert_eq!(original, restored);
 }
+
+#[test]
+fn invalid_utf8() {
+    let obj: ArrayObject = vec!["ab".to_string(), "cd".to_string()].into();
+    let mut binary = obj.pack_as_it_is();
+    binary[0] = 0xC3;
+    let err = ArrayObject::unpack(binary).err().unwrap();
+    assert_eq!(err.to_string(), "The data is either broken or wrong.");
+
+    let obj: ArrayObject = vec!["abc"; 10].into();
+    let mut binary = obj.pack();
+    assert!(DataType::describe_footer(&binary).contains("dictionary"));
+    let n = binary.len();
+    binary[n - 3] = 200;
+    assert!(ArrayObject::unpack(binary).is_err());
+    assert!(ArrayObject::unpack(vec![]).is_err());
+
+    let err = ArrayObject::from_utf8(vec![b"ok".to_vec(), vec![0xFF, 0xFE]])
+        .err()
+        .unwrap();
+    assert!(err.to_string().starts_with("The element 1 is not"));
+}
+
+#[test]
+fn string_and_bytes() {
+    let obj: ArrayObject = vec!["α".to_string(), "".to_string()].into();
+    let obj = obj.cast(DataType::Bytes, CastOption::Checked).unwrap();
+    let restored: Vec<Vec<u8>> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec!["α".as_bytes().to_vec(), vec![]]);
+    let obj = obj.cast(DataType::String, CastOption::Checked).unwrap();
+    let restored: Vec<String> = obj.try_into().unwrap();
+    assert_eq!(restored, vec!["α", ""]);
+
+    let obj: ArrayObject = vec![vec![0xFFu8]].into();
+    assert!(obj.cast(DataType::String, CastOption::Checked).is_err());
+
+    let original = vec![std::ffi::OsString::from("path")];
+    let obj: ArrayObject = original.clone().try_into().unwrap();
+    let restored: Vec<std::ffi::OsString> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, original);
+    let restored: Vec<String> = obj.try_into().unwrap();
+    assert_eq!(restored, vec!["path"]);
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        let invalid = vec!["a".into(), std::ffi::OsString::from_vec(vec![0xFF])];
+        let err = ArrayObject::try_from(invalid).err().unwrap();
+        assert!(matches!(err, ArrayObjectError::InvalidString(1)));
+    }
+}
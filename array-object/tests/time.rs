@@ -0,0 +1,55 @@
+use array_object::*;
+use std::time::Duration;
+
+#[test]
+fn duration() {
+    let original: Vec<Duration> = (0..1000)
+        .map(|i| Duration::from_millis(1_700_000_000_000 + 10 * i))
+        .collect();
+    let obj: ArrayObject = original.clone().into();
+    assert_eq!(obj.unit(), Some(Unit::Duration(TimeUnit::Nanosecond)));
+    let binary = obj.clone().pack();
+    assert_eq!(DataType::describe_footer(&binary), "Array with unit");
+    let raw: ArrayObject = original.iter().map(|x| x.as_nanos() as i64).collect();
+    assert!(binary.len() * 3 < raw.pack().len() * 2);
+    let unpacked = ArrayObject::unpack(binary).unwrap();
+    assert_eq!(unpacked, obj);
+    let restored: Vec<Duration> = unpacked.clone().try_into().unwrap();
+    assert_eq!(restored, original);
+    let first: Duration = unpacked.iter().next().unwrap().unwrap();
+    assert_eq!(first, original[0]);
+
+    let restored: Vec<Duration> = ArrayObject::unpack(obj.pack_as_it_is())
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn unit_check() {
+    let obj: ArrayObject = vec![1i64, 2].into();
+    assert!(Vec::<Duration>::try_from(obj.clone()).is_err());
+    let obj = obj.with_unit(Unit::Duration(TimeUnit::Second)).unwrap();
+    let restored: Vec<Duration> = obj.clone().try_into().unwrap();
+    assert_eq!(
+        restored,
+        vec![Duration::from_secs(1), Duration::from_secs(2)]
+    );
+    let restored: Vec<i64> = obj.clone().try_into().unwrap();
+    assert_eq!(restored, vec![1, 2]);
+
+    let other = obj
+        .clone()
+        .with_unit(Unit::Timestamp(TimeUnit::Second))
+        .unwrap();
+    let err = Vec::<Duration>::try_from(other.clone()).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "The wrong unit: expected Duration(Nanosecond), found Timestamp(Second)."
+    );
+    assert!(vec![obj, other].concat(0).is_err());
+
+    let obj: ArrayObject = vec![1.5f64].into();
+    assert!(obj.with_unit(Unit::Date).is_err());
+}